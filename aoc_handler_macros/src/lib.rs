@@ -0,0 +1,86 @@
+#![warn(clippy::pedantic, clippy::nursery)]
+#![warn(clippy::todo)]
+
+//! The attribute macros of `aoc_handler`. Use them through `aoc_handler` with the `registry` feature, rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ItemImpl, LitInt, Token, parse::Parser, punctuated::Punctuated, spanned::Spanned};
+
+/// Registers a solver for a day, for `Handler::from_registry` to find. Goes on an `impl` of one of the solver traits.
+///
+/// ```ignore
+/// #[aoc_handler::day(2024, 5)]
+/// impl DaySolver for DayImpl { /* ... */ }
+/// ```
+#[proc_macro_attribute]
+pub fn day(attr: TokenStream, item: TokenStream) -> TokenStream {
+    day_impl(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn day_impl(
+    attr: proc_macro2::TokenStream,
+    item: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let key = Punctuated::<LitInt, Token![,]>::parse_terminated.parse2(attr)?;
+    let [year, day] = [key.get(0), key.get(1)].map(|lit| {
+        lit.ok_or_else(|| {
+            syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "Expected a year and a day, e.g. #[day(2024, 5)]",
+            )
+        })
+    });
+    let (year, day) = (year?, day?);
+    if key.len() > 2 {
+        return Err(syn::Error::new(
+            key[2].span(),
+            "Expected only a year and a day, e.g. #[day(2024, 5)]",
+        ));
+    }
+
+    let item: ItemImpl = syn::parse2(item)?;
+    let not_a_solver = || {
+        syn::Error::new(
+            item.span(),
+            "Expected an impl of DaySolver, ParsedDaySolver or CombinedDaySolver",
+        )
+    };
+    let (_, trait_path, _) = item.trait_.as_ref().ok_or_else(not_a_solver)?;
+    let wrap = match trait_path
+        .segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+        .as_deref()
+    {
+        Some("DaySolver") => quote!(WrapSolver),
+        Some("ParsedDaySolver") => quote!(WrapParsedSolver),
+        Some("CombinedDaySolver") => quote!(WrapCombinedSolver),
+        _ => return Err(not_a_solver()),
+    };
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "Generic solvers can't be registered, register a concrete type instead",
+        ));
+    }
+
+    let self_ty = &item.self_ty;
+    Ok(quote! {
+        #item
+
+        const _: () = {
+            const KEY: (::aoc_handler::Year, ::aoc_handler::Day) =
+                ::aoc_handler::__private::checked_key(#year, #day);
+            ::aoc_handler::__private::inventory::submit! {
+                ::aoc_handler::__private::Registration::new(
+                    KEY,
+                    <#self_ty as ::aoc_handler::#wrap>::wrap,
+                    ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#self_ty)),
+                )
+            }
+        };
+    })
+}
@@ -3,20 +3,22 @@ use crate::{
     execution::{
         Request,
         result::{
-            AcquisitionError, CheckReturn, CheckedRunReturn, DayReturn, PartOutput, PartReturn,
-            RunReturn, Unchecked,
+            AcquisitionError, CaseReturn, CheckReturn, CheckedRunReturn, DayReturn, FuzzReturn,
+            PartOutcome, PartOutput, PartReturn, RunReturn, Unchecked,
         },
         solution_part::CheckError,
     },
     time_key::PartInternal,
 };
 
+use super::style::{bold, branch, corner, dim, fail, pass, rule, timing, underline, warn};
+
 pub fn display_day_chunk(chunk: &[(usize, DayReturn)]) -> String {
     let (day_start, [p1, p2]) = &chunk[0];
     let day_start = format!("{day_start}");
 
     let (length, day_print) = if chunk.len() == 1 {
-        (day_start.len(), format!("[1m{day_start}[22m"))
+        (day_start.len(), bold(&day_start))
     } else {
         let (day_end, _) = chunk.last().unwrap();
         let day_end = format!("{day_end}");
@@ -24,29 +26,50 @@ pub fn display_day_chunk(chunk: &[(usize, DayReturn)]) -> String {
 
         (
             len,
-            format!("[4m[1m{day_start}[22m-[1m{day_end}[22m[24m"),
+            underline(format!("{}-{}", bold(&day_start), bold(&day_end))),
         )
     };
 
     let parts_print = if p1 == p2 {
-        format!("- {}", display_part_return(p1))
+        format!("- {}", display_part_return(p1, false))
     } else {
         format!(
-            "┬ {}\n{:5} └ {}",
-            display_part_return(p1),
+            "{} {}\n{:5} {} {}",
+            branch(),
+            display_part_return(p1, false),
             "",
-            display_part_return(p2)
+            corner(),
+            display_part_return(p2, false)
         )
     };
     let length = 5usize.saturating_sub(length);
     format!("{:length$}{day_print} {parts_print}", "")
 }
 
-pub fn display_part_return(pr: &PartReturn) -> String {
-    match pr {
-        Err(ae) => format!("[1m{ae}[22m"),
-        Ok(po) => format!("{po}"),
-    }
+/// Output captured from the solver is only shown if the part failed, unless `always_show_captured` is set.
+/// The same goes for the art of letter answers, which is also shown if not all letters were recognised.
+pub fn display_part_return(pr: &PartReturn, always_show_captured: bool) -> String {
+    let PartOutput { outcome, captured } = match pr {
+        Err(ae) => return bold(ae),
+        Ok(output) => output,
+    };
+
+    let art = match outcome.run_return().map(|rr| &rr.solution_part) {
+        Some(SolutionPart::Letters(art)) if always_show_captured || !art.is_recognised() => {
+            art.art()
+        }
+        _ => "",
+    };
+    let captured = if always_show_captured || outcome.failed() {
+        captured.as_str()
+    } else {
+        ""
+    };
+    art.lines()
+        .chain(captured.lines())
+        .fold(format!("{outcome}"), |acc, line| {
+            format!("{acc}\n{:8}{} {line}", "", dim(rule()))
+        })
 }
 
 impl std::fmt::Display for AcquisitionError {
@@ -58,7 +81,7 @@ impl std::fmt::Display for AcquisitionError {
     }
 }
 
-impl std::fmt::Display for PartOutput {
+impl std::fmt::Display for PartOutcome {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Checked(cr) => write!(f, "{cr}"),
@@ -70,9 +93,96 @@ impl std::fmt::Display for PartOutput {
 impl std::fmt::Display for CheckReturn {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Passed => write!(f, "[32m[1mPassed[22m[39m"),
-            Self::Failed(ce) => write!(f, "[31m[1mFailed:[22m {ce}[39m"),
+            Self::Passed(..) => write!(f, "{}", pass(bold("Passed"))),
+            Self::Failed(ce) => write!(f, "{}", fail(format!("{} {ce}", bold("Failed:")))),
             Self::Unchecked(uc) => write!(f, "{uc}"),
+            Self::Cases(cases) => write!(f, "{}", display_cases("cases", cases)),
+            Self::Implementations(cases) => {
+                write!(f, "{}", display_cases("implementations", cases))
+            }
+            Self::Fuzzed(fr) => write!(f, "{fr}"),
+        }
+    }
+}
+
+fn display_cases(noun: &str, cases: &[CaseReturn]) -> String {
+    let passed = cases
+        .iter()
+        .filter(|case| matches!(case.check_return, CheckReturn::Passed(..)))
+        .count();
+    let skipped: Vec<&str> = cases
+        .iter()
+        .filter(|case| {
+            matches!(
+                case.check_return,
+                CheckReturn::Unchecked(Unchecked::Unimplemented)
+            )
+        })
+        .map(|case| case.name.as_str())
+        .collect();
+    if passed + skipped.len() == cases.len() {
+        let all = pass(format!("{} all {passed} {noun}", bold("Passed")));
+        return if skipped.is_empty() {
+            all
+        } else {
+            format!("{all}{}", dim(format!(", skipped {}", skipped.join(", "))))
+        };
+    }
+    cases.iter().fold(
+        format!(
+            "{} {noun} passed",
+            bold(format!("{passed}/{}", cases.len()))
+        ),
+        |acc, CaseReturn { name, check_return }| {
+            //? Nested listings, e.g. the cases of an implementation, are indented further
+            let check_return = format!("{check_return}").replace('\n', "\n    ");
+            format!("{acc}\n{:8}{} {check_return}", "", dim(format!("{name}:")))
+        },
+    )
+}
+
+impl std::fmt::Display for FuzzReturn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Agreed { runs } => write!(
+                f,
+                "{}",
+                pass(format!(
+                    "{}, implementations agreed on {runs} generated inputs",
+                    bold("Passed")
+                ))
+            ),
+            Self::Disagreed {
+                input,
+                saved_to,
+                answers,
+            } => {
+                write!(
+                    f,
+                    "{}",
+                    fail(format!(
+                        "{} Implementations disagree on a generated input",
+                        bold("Failed:")
+                    ))
+                )?;
+                match saved_to {
+                    Ok(path) => write!(f, " {}", dim(format!("Saved to {}", path.display())))?,
+                    Err(ie) => write!(f, " {}", fail(ie))?,
+                }
+                for (name, answer) in answers {
+                    write!(
+                        f,
+                        "\n{:8}{} {}",
+                        "",
+                        dim(format!("{name}:")),
+                        underline(answer)
+                    )?;
+                }
+                for line in input.lines() {
+                    write!(f, "\n{:8}{} {line}", "", dim(rule()))?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -80,15 +190,29 @@ impl std::fmt::Display for CheckReturn {
 impl std::fmt::Display for CheckedRunReturn {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Ok(rr)
+            Self::Ok { ret: rr, .. }
             | Self::Unchecked {
                 reason: Unchecked::Elided,
                 ret: rr,
             } => write!(f, "{rr}"),
 
-            Self::Unchecked { reason, ret } => write!(f, "{ret} [2m{reason}[22m"),
-            Self::CheckFailed(ce) => write!(f, "[31m[1mTest Failed:[22m {ce}[39m"),
-            Self::RunFailed(ie) => write!(f, "[31m[1mError:[22m {ie}[39m"),
+            Self::Unchecked { reason, ret } => write!(f, "{ret} {}", dim(reason)),
+            Self::CheckFailed(ce) => {
+                write!(f, "{}", fail(format!("{} {ce}", bold("Test Failed:"))))
+            }
+            Self::CasesFailed(cases) => {
+                write!(
+                    f,
+                    "{} {}",
+                    fail(bold("Test Failed:")),
+                    display_cases("checks", cases)
+                )
+            }
+            Self::Disagreement(run_returns) => run_returns.iter().try_fold(
+                write!(f, "{}", fail(bold("Implementations disagree:")))?,
+                |(), (name, rr)| write!(f, "\n{:8}{} {rr}", "", dim(format!("{name}:"))),
+            ),
+            Self::RunFailed(ie) => write!(f, "{}", fail(format!("{} {ie}", bold("Error:")))),
         }
     }
 }
@@ -98,36 +222,82 @@ impl std::fmt::Display for RunReturn {
         let Self {
             solution_part,
             time_taken,
+            shared_time,
+            parse_time,
+            alternative_times,
         } = self;
-        let time_print = if *time_taken >= std::time::Duration::from_secs(1) {
-            format!("[31m{time_taken:>8.2?}[39m")
-        } else if *time_taken >= std::time::Duration::from_millis(10) {
-            format!("[33m{time_taken:>8.2?}[39m")
-        } else {
-            format!("[32m{time_taken:>8.2?}[39m")
-        };
         write!(
             f,
-            "[2m{time_print}[22m [1m[32m{solution_part:48}[39m[22m"
-        )
+            "{} {}",
+            dim(display_time(*time_taken)),
+            bold(pass(format!("{solution_part:48}")))
+        )?;
+        if *shared_time {
+            write!(f, " {}", dim("Timed for both parts"))?;
+        }
+        if let Some(parse_time) = parse_time {
+            write!(
+                f,
+                " {}",
+                dim(format!("Parsed in {}", display_time(*parse_time)))
+            )?;
+        }
+        for (name, time) in alternative_times {
+            match time {
+                Some(time) => write!(f, " {} {}", dim(format!("{name}:")), display_time(*time))?,
+                None => write!(f, " {}", dim(format!("{name}: skipped")))?,
+            }
+        }
+        Ok(())
     }
 }
 
+pub fn display_time(time: std::time::Duration) -> String {
+    timing(format!("{time:>8.2?}"), time)
+}
+
 impl std::fmt::Display for Unchecked {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Elided => write!(f, "[32mTest elided.[39m"),
+            Self::Elided => write!(f, "{}", pass("Test elided.")),
             Self::ElideMismatch(sp) => write!(
                 f,
-                "[33mTried to elide test (empty string), but provided test answer ([4m{sp}[24m)[39m"
+                "{}",
+                warn(format!(
+                    "Tried to elide test (empty string), but provided test answer ({})",
+                    underline(sp)
+                ))
+            ),
+            Self::MissingInput => write!(f, "{}", warn("No test input provided, unable to test")),
+            Self::MissingOuput(sp) => write!(
+                f,
+                "{}",
+                warn(format!(
+                    "Test returned {}, no answer to check against",
+                    underline(sp)
+                ))
+            ),
+            Self::UnreadableInput(path) => write!(
+                f,
+                "{}",
+                warn(format!(
+                    "Unable to read test input from {}",
+                    underline(path.display())
+                ))
+            ),
+            Self::Partial { passed, unchecked } => write!(
+                f,
+                "{}",
+                warn(format!(
+                    "Passed {passed}, but {unchecked} unchecked (no answer to check against)"
+                ))
+            ),
+            Self::Unimplemented => write!(f, "{}", dim("Skipped, unimplemented")),
+            Self::NothingToCompare => write!(
+                f,
+                "{}",
+                warn("No alternative implementations registered, nothing to compare")
             ),
-            Self::MissingInput => write!(f, "[33mNo test input provided, unable to test[39m"),
-            Self::MissingOuput(sp) => {
-                write!(
-                    f,
-                    "[33mTest returned [4m{sp}[24m, no answer to check against[39m"
-                )
-            }
         }
     }
 }
@@ -136,25 +306,33 @@ impl std::fmt::Display for CheckError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Incorrect(a, b, None) => {
-                write!(f, "Result was [4m{a}[24m, should be [4m{b}[24m")
+                write!(f, "Result was {}, should be {}", underline(a), underline(b))
             }
             Self::Incorrect(a, b, Some(std::cmp::Ordering::Greater)) => {
                 write!(
                     f,
-                    "Result was [4m{a}[24m, which is [1mtoo high[22m. Should be [4m{b}[24m"
+                    "Result was {}, which is {}. Should be {}",
+                    underline(a),
+                    bold("too high"),
+                    underline(b)
                 )
             }
             Self::Incorrect(a, b, Some(std::cmp::Ordering::Less)) => {
                 write!(
                     f,
-                    "Result was [4m{a}[24m, which is [1mtoo low[22m. Should be [4m{b}[24m"
+                    "Result was {}, which is {}. Should be {}",
+                    underline(a),
+                    bold("too low"),
+                    underline(b)
                 )
             }
             Self::WrongFormat(a, b) => write!(
                 f,
-                "Result [4m{a}[24m was of a [1mdifferent format[22m ([4m{}[24m) than the provided solution ([4m{}[24m)",
-                a.variant_name(),
-                b.variant_name()
+                "Result {} was of a {} ({}) than the provided solution ({})",
+                underline(a),
+                bold("different format"),
+                underline(a.variant_name()),
+                underline(b.variant_name())
             ),
             Self::Incorrect(_, _, Some(std::cmp::Ordering::Equal)) => {
                 unreachable!("CheckError was marked as Incorrect, but both candidates were equal.")
@@ -169,6 +347,7 @@ impl SolutionPart {
             Self::Unimplemented => "Unimplemented".to_string(), //Should definitionally never be called
             Self::Number(_) => "Number".to_string(),
             Self::String(_) => "String".to_string(),
+            Self::Letters(_) => "Letters".to_string(),
         }
     }
 }
@@ -180,6 +359,7 @@ impl std::fmt::Display for SolutionPart {
             Self::Unimplemented => write!(f, "Unimplemented"),
             Self::Number(n) => write!(f, "{n:<desired_width$}"),
             Self::String(s) => write!(f, "{s:<desired_width$}"),
+            Self::Letters(art) => write!(f, "{:<desired_width$}", art.letters()),
         }
     }
 }
@@ -196,8 +376,8 @@ impl std::fmt::Display for PartInternal {
 impl std::fmt::Display for Request {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Check => write!(f, "[1m[4mTesting[22m[24m"),
-            Self::CheckAndRun => write!(f, "[1m[4mRunning[22m[24m"),
+            Self::Check => write!(f, "{}", bold(underline("Testing"))),
+            Self::CheckAndRun => write!(f, "{}", bold(underline("Running"))),
         }
     }
 }
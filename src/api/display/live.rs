@@ -0,0 +1,72 @@
+use std::io::Write;
+
+use crate::{
+    api::display::{display_impls::display_day_chunk, style},
+    execution::result::DayReturn,
+    time_key::{TimeDetailDay, TimeDetailDayAndPart, TimeDetailNone, TimeKey},
+};
+
+/// Renders the days of a year as they complete, for terminals that the theme styles.
+/// A status line below the table shows what is currently running, and consecutive identical days are merged in place.
+pub struct LiveYear {
+    started: std::time::Instant,
+    chunk: Vec<(usize, DayReturn)>,
+    chunk_lines: usize, //How many lines the last chunk takes up, so it can be redrawn
+}
+
+impl LiveYear {
+    /// Prints the header of the year, and starts timing it.
+    pub fn start(time_key: TimeKey<TimeDetailNone>) -> Self {
+        println!("{}", time_key.header());
+        Self {
+            started: std::time::Instant::now(),
+            chunk: Vec::new(),
+            chunk_lines: 0,
+        }
+    }
+
+    /// Replaces the status line with the part that is about to run.
+    pub fn status(&self, time_key: TimeKey<TimeDetailDayAndPart>) {
+        let (_, day, part) = time_key.to_primitive();
+        print!(
+            "{}{}",
+            style::clear_line(),
+            style::dim(format!(
+                "Running day {day} part {part} — {:.2?} elapsed",
+                self.started.elapsed()
+            ))
+        );
+        let _ = std::io::stdout().flush(); //A status line that fails to show isn't worth aborting over
+    }
+
+    /// Prints a completed day, or merges it into the previous line if both turned out identical.
+    pub fn push(&mut self, time_key: TimeKey<TimeDetailDay>, day_return: DayReturn) {
+        let (_, day) = time_key.to_primitive();
+        print!("{}", style::clear_line());
+
+        if self
+            .chunk
+            .last()
+            .is_some_and(|(_, previous)| *previous == day_return)
+        {
+            //? Move up to the start of the previous chunk, and clear everything below
+            print!("{}", style::rewind(self.chunk_lines));
+        } else {
+            self.chunk.clear();
+        }
+        self.chunk.push((day.into(), day_return));
+
+        let rendered = display_day_chunk(&self.chunk);
+        self.chunk_lines = rendered.lines().count();
+        println!("{rendered}");
+    }
+
+    /// Replaces the status line with the total time the year took.
+    pub fn finish(self) {
+        println!(
+            "{}{}\n",
+            style::clear_line(),
+            style::dim(format!("Finished in {:.2?}", self.started.elapsed()))
+        );
+    }
+}
@@ -5,6 +5,10 @@ use crate::{
 };
 
 mod display_impls;
+mod live;
+pub use live::LiveYear;
+pub mod style;
+pub use style::{Colour, Theme};
 mod test;
 
 pub fn render_multiple_year_results(
@@ -38,12 +42,14 @@ pub fn render_day_return(time_key: TimeKey<TimeDetailDay>, day_return: &DayRetur
     time_key.header()
         + "\n"
         + &if p1 == p2 {
-            display_part_return(p1)
+            display_part_return(p1, false)
         } else {
             format!(
-                "[2mPart One[22m - {}\n[2mPart Two[22m - {}",
-                display_part_return(p1),
-                display_part_return(p2)
+                "{} - {}\n{} - {}",
+                style::dim("Part One"),
+                display_part_return(p1, false),
+                style::dim("Part Two"),
+                display_part_return(p2, false)
             )
         }
 }
@@ -53,25 +59,67 @@ pub fn render_part_return(
     time_key: TimeKey<TimeDetailDayAndPart>,
     part_return: &PartReturn,
 ) -> String {
-    time_key.header() + "\n" + &display_part_return(part_return)
+    time_key.header() + "\n" + &display_part_return(part_return, true)
+}
+
+/// Sums up repeated runs of a part on the real input.
+#[cfg(feature = "interactive")]
+pub fn render_bench(
+    time_key: TimeKey<TimeDetailDayAndPart>,
+    mut times: Vec<std::time::Duration>,
+) -> String {
+    times.sort_unstable();
+    let (Some(min), Some(max)) = (times.first(), times.last()) else {
+        return time_key.header();
+    };
+    format!(
+        "{}\n{} runs {} {} {} {} {} {}",
+        time_key.header(),
+        times.len(),
+        style::dim("min"),
+        display_impls::display_time(*min),
+        style::dim("median"),
+        display_impls::display_time(times[times.len() / 2]),
+        style::dim("max"),
+        display_impls::display_time(*max)
+    )
 }
 
 impl TimeKey<TimeDetailNone> {
     fn header(self) -> String {
-        format!("[1m{}[22m", self.year)
+        style::bold(self.year)
     }
 }
 impl TimeKey<TimeDetailDay> {
     fn header(self) -> String {
-        format!("{} — [1mDay {}[22m", self.deref().header(), self.detail.0)
+        format!(
+            "{} — {}",
+            self.deref().header(),
+            style::bold(format!("Day {}", self.detail.0))
+        )
     }
 }
 impl TimeKey<TimeDetailDayAndPart> {
     fn header(self) -> String {
         format!(
-            "{} — [1mPart {}[22m",
+            "{} — {}",
             self.deref().header(),
-            self.detail.part
+            style::bold(format!("Part {}", self.detail.part))
         )
     }
 }
+
+/// Removes all styling escape sequences, for output that shouldn't be coloured regardless of the [`Theme`].
+pub fn strip_styles(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            //? All sequences used here are CSI sequences, which end on their first letter
+            chars.find(char::is_ascii_alphabetic);
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
@@ -0,0 +1,249 @@
+use std::{fmt::Display, sync::RwLock, time::Duration};
+
+static CURRENT: RwLock<Option<Theme>> = RwLock::new(None);
+
+/// How results are styled on the terminal: Whether escape sequences are used at all, which colours carry meaning,
+/// and whether box drawing characters are replaced with ASCII.
+///
+/// The theme applies to everything rendered by the process. Unless one is set with [`Theme::set`], [`Theme::detect`] is used,
+/// so piping the output to a file or CI log leaves out the escape sequences.
+///
+/// ```no_run
+/// use aoc_handler::{Colour, Theme};
+///
+/// Theme::set(Theme::detect().with_pass(Colour::Cyan).with_ascii(true));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    styled: bool,
+    ascii: bool,
+    pass: Colour,
+    fail: Colour,
+    warn: Colour,
+    fast: Colour,
+    medium: Colour,
+    slow: Colour,
+}
+
+/// A terminal foreground colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Ansi256(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            styled: true,
+            ascii: false,
+            pass: Colour::Green,
+            fail: Colour::Red,
+            warn: Colour::Yellow,
+            fast: Colour::Green,
+            medium: Colour::Yellow,
+            slow: Colour::Red,
+        }
+    }
+}
+
+impl Theme {
+    /// The default theme, without any styling if stdout isn't a terminal or `NO_COLOR` is set.
+    pub fn detect() -> Self {
+        use std::io::IsTerminal;
+
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        Self::default().with_styles(std::io::stdout().is_terminal() && !no_color)
+    }
+
+    /// No escape sequences at all, for output that is read by something other than a terminal.
+    pub fn plain() -> Self {
+        Self::default().with_styles(false)
+    }
+
+    /// The theme used for rendering.
+    pub fn current() -> Self {
+        let current = *CURRENT
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        current.unwrap_or_else(|| {
+            *CURRENT
+                .write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .get_or_insert_with(Self::detect)
+        })
+    }
+
+    /// Replaces the theme used for rendering, for the rest of the process.
+    pub fn set(theme: Self) {
+        *CURRENT
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(theme);
+    }
+
+    pub const fn is_styled(self) -> bool {
+        self.styled
+    }
+
+    /// Whether to use escape sequences for colours, bold, dim and underlined text.
+    #[must_use]
+    pub const fn with_styles(mut self, styled: bool) -> Self {
+        self.styled = styled;
+        self
+    }
+
+    /// Whether to replace the box drawing characters that connect parts and captured output with ASCII.
+    #[must_use]
+    pub const fn with_ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// The colour of passed checks.
+    #[must_use]
+    pub const fn with_pass(mut self, pass: Colour) -> Self {
+        self.pass = pass;
+        self
+    }
+
+    /// The colour of failures and errors.
+    #[must_use]
+    pub const fn with_fail(mut self, fail: Colour) -> Self {
+        self.fail = fail;
+        self
+    }
+
+    /// The colour of results that couldn't be verified.
+    #[must_use]
+    pub const fn with_warn(mut self, warn: Colour) -> Self {
+        self.warn = warn;
+        self
+    }
+
+    /// The colours of run times under 10ms, under a second, and anything slower.
+    #[must_use]
+    pub const fn with_timing(mut self, fast: Colour, medium: Colour, slow: Colour) -> Self {
+        self.fast = fast;
+        self.medium = medium;
+        self.slow = slow;
+        self
+    }
+}
+
+impl Colour {
+    fn code(self) -> String {
+        match self {
+            Self::Black => "30".to_owned(),
+            Self::Red => "31".to_owned(),
+            Self::Green => "32".to_owned(),
+            Self::Yellow => "33".to_owned(),
+            Self::Blue => "34".to_owned(),
+            Self::Magenta => "35".to_owned(),
+            Self::Cyan => "36".to_owned(),
+            Self::White => "37".to_owned(),
+            Self::Ansi256(n) => format!("38;5;{n}"),
+            Self::Rgb(r, g, b) => format!("38;2;{r};{g};{b}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Speed {
+    Fast,
+    Medium,
+    Slow,
+}
+
+/// How a run time is judged, by both the terminal and the Markdown output.
+pub fn speed(time: Duration) -> Speed {
+    if time >= Duration::from_secs(1) {
+        Speed::Slow
+    } else if time >= Duration::from_millis(10) {
+        Speed::Medium
+    } else {
+        Speed::Fast
+    }
+}
+
+fn sgr(text: impl Display, on: &str, off: &str) -> String {
+    if Theme::current().styled {
+        format!("[{on}m{text}[{off}m")
+    } else {
+        text.to_string()
+    }
+}
+
+pub fn bold(text: impl Display) -> String {
+    sgr(text, "1", "22")
+}
+
+pub fn dim(text: impl Display) -> String {
+    sgr(text, "2", "22")
+}
+
+pub fn underline(text: impl Display) -> String {
+    sgr(text, "4", "24")
+}
+
+pub fn pass(text: impl Display) -> String {
+    sgr(text, &Theme::current().pass.code(), "39")
+}
+
+pub fn fail(text: impl Display) -> String {
+    sgr(text, &Theme::current().fail.code(), "39")
+}
+
+pub fn warn(text: impl Display) -> String {
+    sgr(text, &Theme::current().warn.code(), "39")
+}
+
+pub fn timing(text: impl Display, time: Duration) -> String {
+    let theme = Theme::current();
+    let colour = match speed(time) {
+        Speed::Fast => theme.fast,
+        Speed::Medium => theme.medium,
+        Speed::Slow => theme.slow,
+    };
+    sgr(text, &colour.code(), "39")
+}
+
+/// Returns to the start of the current line and clears it, for replacing a status line.
+pub fn clear_line() -> &'static str {
+    if Theme::current().styled {
+        "\r[2K"
+    } else {
+        ""
+    }
+}
+
+/// Moves up to the start of the line `lines` above, and clears everything below it, for redrawing what was printed there.
+pub fn rewind(lines: usize) -> String {
+    if Theme::current().styled {
+        format!("[{lines}F[J")
+    } else {
+        String::new()
+    }
+}
+
+/// Joins the first of two parts listed on separate lines.
+pub fn branch() -> &'static str {
+    if Theme::current().ascii { "+" } else { "┬" }
+}
+
+/// Joins the last of two parts listed on separate lines.
+pub fn corner() -> &'static str {
+    if Theme::current().ascii { "`" } else { "└" }
+}
+
+/// Precedes lines of captured output and generated inputs.
+pub fn rule() -> &'static str {
+    if Theme::current().ascii { "|" } else { "│" }
+}
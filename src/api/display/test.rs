@@ -5,7 +5,8 @@ use crate::{
     SolutionPart,
     execution::{
         result::{
-            AcquisitionError, CheckReturn, CheckedRunReturn, PartOutput, RunReturn, Unchecked,
+            AcquisitionError, CaseReturn, CheckReturn, CheckedRunReturn, FuzzReturn, PartOutcome,
+            PartOutput, RunReturn, Unchecked,
         },
         solution_part::CheckError,
     },
@@ -48,10 +49,81 @@ fn print_parts() {
     }
 }
 
+#[test]
+fn plain_and_ascii_themes_leave_out_escapes_and_box_drawing() {
+    let render_all = || {
+        let year = render_year_returns(
+            TimeKey::<TimeDetailNone>::new(2025).unwrap(),
+            generate_day_return_variants(),
+        );
+        generate_part_return_variants()
+            .enumerate()
+            .fold(year, |rendered, (i, part_return)| {
+                let time_key = TimeKey::<TimeDetailDayAndPart>::new(
+                    2024,
+                    (i + 1).min(25).try_into().unwrap(),
+                    2,
+                )
+                .unwrap();
+                rendered + &render_part_return(time_key, &part_return)
+            })
+    };
+    let box_drawing = ['┬', '└', '│'];
+
+    //? The theme is global, so both are rendered in one test instead of racing each other
+    let previous = Theme::current();
+    Theme::set(Theme::plain());
+    let plain = render_all();
+    Theme::set(Theme::plain().with_ascii(true));
+    let ascii = render_all();
+    Theme::set(previous);
+
+    assert!(!plain.contains('\x1b'));
+    assert!(box_drawing.iter().all(|c| plain.contains(*c)));
+    assert!(!ascii.contains('\x1b'));
+    assert!(!ascii.contains(box_drawing));
+}
+
 fn generate_day_return_variants() -> impl Iterator<Item = DayReturn> {
     itertools::Itertools::tuples(generate_part_return_variants()).map(|tup: (_, _)| tup.into())
 }
 
+fn alternative_times() -> Vec<(String, Option<std::time::Duration>)> {
+    vec![
+        (
+            "Naive".to_string(),
+            Some(std::time::Duration::from_millis(250)),
+        ),
+        ("Unfinished".to_string(), None),
+    ]
+}
+
+fn passed() -> CheckReturn {
+    CheckReturn::Passed(SolutionPart::from(42), SolutionPart::from(42))
+}
+
+fn checked_run_return(check_return: CheckReturn, rr: RunReturn) -> CheckedRunReturn {
+    match check_return {
+        CheckReturn::Failed(ce) => CheckedRunReturn::CheckFailed(ce),
+        CheckReturn::Cases(cases) | CheckReturn::Implementations(cases)
+            if cases.iter().any(CaseReturn::failed) =>
+        {
+            CheckedRunReturn::CasesFailed(cases)
+        }
+        check @ (CheckReturn::Passed(..)
+        | CheckReturn::Cases(_)
+        | CheckReturn::Implementations(_)) => CheckedRunReturn::Ok {
+            check: Box::new(check),
+            ret: rr,
+        },
+        CheckReturn::Unchecked(uc) => CheckedRunReturn::Unchecked {
+            reason: uc,
+            ret: rr,
+        },
+        CheckReturn::Fuzzed(_) => unreachable!(),
+    }
+}
+
 fn generate_part_return_variants() -> impl Iterator<Item = PartReturn> {
     let mandatory_solution_parts = [
         SolutionPart::from(rust_decimal::Decimal::MAX),
@@ -75,15 +147,18 @@ fn generate_part_return_variants() -> impl Iterator<Item = PartReturn> {
         CheckReturn::Unchecked(Unchecked::ElideMismatch("tempor incididunt".into())),
         CheckReturn::Unchecked(Unchecked::MissingInput),
         CheckReturn::Unchecked(Unchecked::MissingOuput("dolor sit amet".into())),
-        CheckReturn::Passed,
-        CheckReturn::Passed,
-        CheckReturn::Passed,
-        CheckReturn::Passed,
-        CheckReturn::Passed,
-        CheckReturn::Passed,
-        CheckReturn::Passed,
+        CheckReturn::Unchecked(Unchecked::UnreadableInput(
+            "examples/2024/day05_1.txt".into(),
+        )),
+        passed(),
+        passed(),
+        passed(),
+        passed(),
+        passed(),
     ]
     .into_iter()
+    .chain(generate_case_returns().map(CheckReturn::Cases))
+    .chain(generate_implementation_returns().map(CheckReturn::Implementations))
     .chain(check_errors.into_iter().map(CheckReturn::Failed));
 
     let mandatory_times = [
@@ -102,25 +177,32 @@ fn generate_part_return_variants() -> impl Iterator<Item = PartReturn> {
     let run_returns = solution_parts
         .take(run_return_count)
         .zip(times.take(run_return_count))
-        .map(|(solution_part, time_taken)| RunReturn {
+        .enumerate()
+        .map(|(i, (solution_part, time_taken))| RunReturn {
             solution_part,
             time_taken,
+            shared_time: i % 4 == 1,
+            parse_time: (i % 3 == 0).then_some(std::time::Duration::from_micros(42)),
+            alternative_times: if i % 5 == 2 {
+                alternative_times()
+            } else {
+                Vec::new()
+            },
         });
 
     let checked_run_returns = run_returns
         .zip(check_returns.clone())
-        .map(|(rr, cr)| match cr {
-            CheckReturn::Passed => CheckedRunReturn::Ok(rr),
-            CheckReturn::Failed(ce) => CheckedRunReturn::CheckFailed(ce),
-            CheckReturn::Unchecked(uc) => CheckedRunReturn::Unchecked {
-                reason: uc,
-                ret: rr,
-            },
-        });
+        .map(|(rr, cr)| checked_run_return(cr, rr));
+
+    let part_outcomes = check_returns
+        .map(PartOutcome::Checked)
+        .chain(generate_fuzz_returns().map(|fr| PartOutcome::Checked(CheckReturn::Fuzzed(fr))))
+        .chain(checked_run_returns.map(PartOutcome::CheckedAndRan))
+        .chain(std::iter::once(PartOutcome::CheckedAndRan(
+            generate_disagreement(),
+        )));
 
-    let part_ouputs = check_returns
-        .map(PartOutput::Checked)
-        .chain(checked_run_returns.map(PartOutput::CheckedAndRan));
+    let part_ouputs = attach_captured_output(part_outcomes);
 
     let acquisition_errors = [
         AcquisitionError::NotMapped,
@@ -138,3 +220,95 @@ fn generate_part_return_variants() -> impl Iterator<Item = PartReturn> {
         .map(Ok)
         .chain(acquisition_errors.into_iter().map(Err))
 }
+
+fn attach_captured_output(
+    outcomes: impl Iterator<Item = PartOutcome>,
+) -> impl Iterator<Item = PartOutput> {
+    outcomes.enumerate().map(|(i, outcome)| PartOutput {
+        outcome,
+        captured: if i % 7 == 3 {
+            "grid is 7x7\nvisited 22 cells".to_string()
+        } else {
+            String::new()
+        },
+    })
+}
+
+fn generate_case_returns() -> impl Iterator<Item = Vec<CaseReturn>> + Clone {
+    let case = |name: &str, check_return| CaseReturn {
+        name: name.to_string(),
+        check_return,
+    };
+
+    [
+        vec![case("Example", passed()), case("Larger example", passed())],
+        vec![
+            case("Example", passed()),
+            case(
+                "Line 2",
+                CheckReturn::Failed(CheckError::Incorrect(
+                    5.into(),
+                    3.into(),
+                    Some(std::cmp::Ordering::Greater),
+                )),
+            ),
+            case("Line 3", CheckReturn::Unchecked(Unchecked::MissingInput)),
+        ],
+    ]
+    .into_iter()
+}
+
+fn generate_implementation_returns() -> impl Iterator<Item = Vec<CaseReturn>> + Clone {
+    let implementation = |name: &str, check_return| CaseReturn {
+        name: name.to_string(),
+        check_return,
+    };
+
+    [
+        vec![
+            implementation("Primary", passed()),
+            implementation("Naive", passed()),
+        ],
+        generate_case_returns()
+            .map(CheckReturn::Cases)
+            .zip(["Primary", "Naive"])
+            .map(|(check_return, name)| implementation(name, check_return))
+            .collect(),
+    ]
+    .into_iter()
+}
+
+fn generate_disagreement() -> CheckedRunReturn {
+    CheckedRunReturn::Disagreement(
+        [("Primary", 1234), ("Naive", 1235)]
+            .into_iter()
+            .map(|(name, answer)| {
+                (
+                    name.to_string(),
+                    RunReturn {
+                        solution_part: answer.into(),
+                        time_taken: std::time::Duration::from_millis(3),
+                        shared_time: false,
+                        parse_time: None,
+                        alternative_times: Vec::new(),
+                    },
+                )
+            })
+            .collect(),
+    )
+}
+
+fn generate_fuzz_returns() -> impl Iterator<Item = FuzzReturn> {
+    [
+        FuzzReturn::Agreed { runs: 1000 },
+        FuzzReturn::Disagreed {
+            input: "3 4\n2 5".to_string(),
+            saved_to: Ok("examples/2024/day01_1.counterexample.txt".into()),
+            answers: vec![
+                ("Primary".to_string(), 11.into()),
+                ("Brute force".to_string(), 12.into()),
+            ],
+        },
+    ]
+    .into_iter()
+}
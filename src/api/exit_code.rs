@@ -0,0 +1,341 @@
+use crate::{
+    Handler, HandlerError, Report, Reports,
+    execution::result::{
+        AcquisitionError, CheckReturn, CheckedRunReturn, FuzzReturn, PartOutcome, PartReturn,
+        Unchecked,
+    },
+};
+
+/// The ways a report can fail, each with its own process exit code.
+/// When a report contains several, the most severe one (the highest code) is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Failure {
+    /// A part couldn't be checked, e.g. due to a missing test answer. Only counted if the [`ExitPolicy`] says so.
+    Unchecked = 1,
+    /// A part was mapped, but left unimplemented. Only counted if the [`ExitPolicy`] says so.
+    Unimplemented = 2,
+    /// A part gave the wrong answer for a test input, or for the expected answer it was run against.
+    TestFailed = 3,
+    /// Implementations of a part disagreed with each other, on the real input or while fuzzing.
+    Mismatch = 4,
+    /// An input couldn't be acquired: The real input, a custom input, or a test input file. Also used for invalid dates.
+    InputError = 5,
+    /// A solver panicked. Same code as an uncaught panic.
+    Panicked = 101,
+}
+
+/// Which results besides outright failures should fail a CI job.
+///
+/// By default (and through [`ExitPolicy::lenient`]), neither unimplemented nor unchecked parts count as failures,
+/// since a year in progress is full of them. Deliberately elided tests never count, and neither do days without a solver.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExitPolicy {
+    pub unimplemented_fails: bool,
+    pub unchecked_fails: bool,
+}
+
+impl ExitPolicy {
+    /// Only failures, mismatches, input errors and panics fail.
+    pub fn lenient() -> Self {
+        Self::default()
+    }
+
+    /// Unimplemented and unchecked parts fail as well.
+    pub fn strict() -> Self {
+        Self {
+            unimplemented_fails: true,
+            unchecked_fails: true,
+        }
+    }
+}
+
+impl From<Failure> for std::process::ExitCode {
+    fn from(value: Failure) -> Self {
+        Self::from(value as u8)
+    }
+}
+
+impl Report {
+    /// The most severe failure in this report, according to the policy.
+    pub fn failure(&self, policy: ExitPolicy) -> Option<Failure> {
+        self.parts()
+            .filter_map(|(.., part_return)| part_failure(part_return, policy))
+            .max()
+    }
+
+    /// Sums the report up as a process exit code, zero if nothing failed according to the policy.
+    pub fn exit_code(&self, policy: ExitPolicy) -> std::process::ExitCode {
+        self.failure(policy)
+            .map_or(std::process::ExitCode::SUCCESS, Into::into)
+    }
+}
+
+impl Handler<'_> {
+    /// Produces a report, prints it in the handler's [`OutputFormat`](crate::OutputFormat), and sums it up as an exit code, for use in CI.
+    /// Errors are printed instead, invalid dates and unavailable inputs exit as [`Failure::InputError`], panics as [`Failure::Panicked`].
+    ///
+    /// ```no_run
+    /// use aoc_handler::{Day, ExitPolicy, Handler, Solver, Year};
+    ///
+    /// fn main() -> std::process::ExitCode {
+    ///     let mapper = |_year: Year, _day: Day| -> Option<Solver> { None };
+    ///     Handler::new(&mapper).exit_code(ExitPolicy::lenient(), |reports| reports.check_year(2024))
+    /// }
+    /// ```
+    pub fn exit_code(
+        &self,
+        policy: ExitPolicy,
+        request: impl FnOnce(&Reports) -> Result<Report, HandlerError>,
+    ) -> std::process::ExitCode {
+        match request(&self.reports()) {
+            Ok(report) => {
+                let exit_code = report.exit_code(policy);
+                self.print_report(Ok(report));
+                exit_code
+            }
+            Err(he) => {
+                let failure = match he {
+                    HandlerError::TimeKey(_) | HandlerError::Input(_) => Failure::InputError,
+                    HandlerError::Panicked(_) => Failure::Panicked,
+                };
+                self.print_report(Err(he));
+                failure.into()
+            }
+        }
+    }
+}
+
+fn part_failure(part_return: &PartReturn, policy: ExitPolicy) -> Option<Failure> {
+    match part_return {
+        Err(AcquisitionError::NotMapped) => None,
+        Err(AcquisitionError::Unimplemented) => {
+            policy.unimplemented_fails.then_some(Failure::Unimplemented)
+        }
+        Ok(output) => match &output.outcome {
+            PartOutcome::Checked(cr) => check_failure(cr, policy),
+            PartOutcome::CheckedAndRan(crr) => match crr {
+                CheckedRunReturn::Ok { .. } => None,
+                CheckedRunReturn::Unchecked { reason, .. } => unchecked_failure(reason, policy),
+                CheckedRunReturn::CheckFailed(_) | CheckedRunReturn::CasesFailed(_) => {
+                    Some(Failure::TestFailed)
+                }
+                CheckedRunReturn::Disagreement(_) => Some(Failure::Mismatch),
+                CheckedRunReturn::RunFailed(_) => Some(Failure::InputError),
+            },
+        },
+    }
+}
+
+fn check_failure(check_return: &CheckReturn, policy: ExitPolicy) -> Option<Failure> {
+    match check_return {
+        CheckReturn::Passed(..) | CheckReturn::Fuzzed(FuzzReturn::Agreed { .. }) => None,
+        CheckReturn::Unchecked(reason) => unchecked_failure(reason, policy),
+        CheckReturn::Failed(_) => Some(Failure::TestFailed),
+        CheckReturn::Cases(cases) | CheckReturn::Implementations(cases) => cases
+            .iter()
+            .filter_map(|case| check_failure(&case.check_return, policy))
+            .max(),
+        CheckReturn::Fuzzed(FuzzReturn::Disagreed { .. }) => Some(Failure::Mismatch),
+    }
+}
+
+fn unchecked_failure(reason: &Unchecked, policy: ExitPolicy) -> Option<Failure> {
+    match reason {
+        Unchecked::Elided => None,
+        Unchecked::Unimplemented => policy.unimplemented_fails.then_some(Failure::Unimplemented),
+        Unchecked::UnreadableInput(_) => Some(Failure::InputError),
+        Unchecked::ElideMismatch(_)
+        | Unchecked::MissingInput
+        | Unchecked::MissingOuput(_)
+        | Unchecked::NothingToCompare
+        | Unchecked::Partial { .. } => policy.unchecked_fails.then_some(Failure::Unchecked),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExitPolicy, Failure};
+    use crate::{
+        Report, Results, SolutionPart,
+        execution::{
+            Request,
+            result::{
+                AcquisitionError, CaseReturn, CheckError, CheckReturn, CheckedRunReturn,
+                FuzzReturn, PartOutcome, PartOutput, PartReturn, RunReturn, Unchecked,
+            },
+        },
+        input_handler::InputError,
+    };
+
+    fn failure(part_return: PartReturn, policy: ExitPolicy) -> Option<Failure> {
+        Report::new(Request::Check, Results::Part(2015, 1, 1, part_return)).failure(policy)
+    }
+
+    fn checked(check_return: CheckReturn) -> PartOutput {
+        PartOutput {
+            outcome: PartOutcome::Checked(check_return),
+            captured: String::new(),
+        }
+    }
+
+    fn ran(checked_run_return: CheckedRunReturn) -> PartOutput {
+        PartOutput {
+            outcome: PartOutcome::CheckedAndRan(checked_run_return),
+            captured: String::new(),
+        }
+    }
+
+    fn run_return() -> RunReturn {
+        RunReturn {
+            solution_part: SolutionPart::from(1),
+            time_taken: std::time::Duration::from_millis(1),
+            shared_time: false,
+            parse_time: None,
+            alternative_times: Vec::new(),
+        }
+    }
+
+    fn incorrect() -> CheckError {
+        CheckError::Incorrect(SolutionPart::from(1), SolutionPart::from(2), None)
+    }
+
+    #[test]
+    fn failures_fail_under_any_policy() {
+        let expectations = [
+            (
+                Ok(checked(CheckReturn::Failed(incorrect()))),
+                Failure::TestFailed,
+            ),
+            (
+                Ok(ran(CheckedRunReturn::CheckFailed(incorrect()))),
+                Failure::TestFailed,
+            ),
+            (
+                Ok(ran(CheckedRunReturn::CasesFailed(vec![CaseReturn {
+                    name: "Example".to_owned(),
+                    check_return: CheckReturn::Failed(incorrect()),
+                }]))),
+                Failure::TestFailed,
+            ),
+            (
+                Ok(ran(CheckedRunReturn::Disagreement(vec![
+                    ("Primary".to_owned(), run_return()),
+                    ("Naive".to_owned(), run_return()),
+                ]))),
+                Failure::Mismatch,
+            ),
+            (
+                Ok(checked(CheckReturn::Fuzzed(FuzzReturn::Disagreed {
+                    input: "7".to_owned(),
+                    saved_to: Ok("day01_1.counterexample.txt".into()),
+                    answers: Vec::new(),
+                }))),
+                Failure::Mismatch,
+            ),
+            (
+                Ok(ran(CheckedRunReturn::RunFailed(InputError::NotCached {
+                    year: 2015,
+                    day: 1,
+                }))),
+                Failure::InputError,
+            ),
+            (
+                Ok(checked(CheckReturn::Unchecked(Unchecked::UnreadableInput(
+                    "day01.txt".into(),
+                )))),
+                Failure::InputError,
+            ),
+        ];
+        for (part_return, expected) in expectations {
+            for policy in [ExitPolicy::lenient(), ExitPolicy::strict()] {
+                assert_eq!(failure(part_return.clone(), policy), Some(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn unchecked_and_unimplemented_parts_follow_the_policy() {
+        let expectations = [
+            (
+                Ok(checked(CheckReturn::Unchecked(Unchecked::MissingInput))),
+                Failure::Unchecked,
+            ),
+            (
+                Ok(ran(CheckedRunReturn::Unchecked {
+                    reason: Unchecked::MissingOuput(SolutionPart::from(1)),
+                    ret: run_return(),
+                })),
+                Failure::Unchecked,
+            ),
+            (Err(AcquisitionError::Unimplemented), Failure::Unimplemented),
+            (
+                Ok(checked(CheckReturn::Implementations(vec![CaseReturn {
+                    name: "Unfinished".to_owned(),
+                    check_return: CheckReturn::Unchecked(Unchecked::Unimplemented),
+                }]))),
+                Failure::Unimplemented,
+            ),
+        ];
+        for (part_return, expected) in expectations {
+            assert_eq!(failure(part_return.clone(), ExitPolicy::lenient()), None);
+            assert_eq!(failure(part_return, ExitPolicy::strict()), Some(expected));
+        }
+    }
+
+    #[test]
+    fn elided_tests_and_missing_solvers_never_fail() {
+        for policy in [ExitPolicy::lenient(), ExitPolicy::strict()] {
+            assert_eq!(
+                failure(
+                    Ok(checked(CheckReturn::Unchecked(Unchecked::Elided))),
+                    policy
+                ),
+                None
+            );
+            assert_eq!(
+                failure(
+                    Ok(ran(CheckedRunReturn::Unchecked {
+                        reason: Unchecked::Elided,
+                        ret: run_return(),
+                    })),
+                    policy
+                ),
+                None
+            );
+            assert_eq!(failure(Err(AcquisitionError::NotMapped), policy), None);
+        }
+    }
+
+    #[test]
+    fn the_most_severe_failure_wins() {
+        let report = Report::new(
+            Request::Check,
+            Results::Day(
+                2015,
+                1,
+                [
+                    Ok(checked(CheckReturn::Failed(incorrect()))),
+                    Err(AcquisitionError::Unimplemented),
+                ],
+            ),
+        );
+        assert_eq!(
+            report.failure(ExitPolicy::strict()),
+            Some(Failure::TestFailed)
+        );
+    }
+
+    #[test]
+    fn exit_codes_are_stable() {
+        let codes = [
+            Failure::Unchecked,
+            Failure::Unimplemented,
+            Failure::TestFailed,
+            Failure::Mismatch,
+            Failure::InputError,
+            Failure::Panicked,
+        ]
+        .map(|failure| failure as u8);
+        assert_eq!(codes, [1, 2, 3, 4, 5, 101]);
+    }
+}
@@ -0,0 +1,527 @@
+use serde::Serialize;
+
+use crate::{
+    Day, Part, Report, Results, SolutionPart, Year,
+    execution::{
+        Request,
+        result::{
+            AcquisitionError, CaseReturn, CheckError, CheckReturn, CheckedRunReturn, DayReturn,
+            FuzzReturn, PartOutcome, PartReturn, RunReturn, Unchecked,
+        },
+    },
+};
+
+impl Report {
+    /// Renders the full result tree as pretty printed JSON, for scripts and dashboards. Requires the `json` feature.
+    ///
+    /// Fields always appear in the same order, and years, days and parts are in ascending order, so that reports can be diffed.
+    /// Timings differ between every run, `timings: false` leaves them out.
+    pub fn to_json(&self, timings: bool) -> String {
+        let years = match self.results() {
+            Results::Years(years) => years
+                .iter()
+                .map(|(year, days)| year_json(*year, days, timings))
+                .collect(),
+            Results::Year(year, days) => vec![year_json(*year, days, timings)],
+            Results::Day(year, day, day_return) => vec![YearJson {
+                year: *year,
+                days: vec![day_json(*day, day_return, timings)],
+            }],
+            Results::Part(year, day, part, part_return) => vec![YearJson {
+                year: *year,
+                days: vec![DayJson {
+                    day: *day,
+                    parts: vec![part_json(*part, part_return, timings)],
+                }],
+            }],
+        };
+        let report = ReportJson {
+            request: match self.request() {
+                Request::Check => "check",
+                Request::CheckAndRun => "run",
+            },
+            years,
+        };
+        serde_json::to_string_pretty(&report).expect("Internal error: Report wasn't serialisable.")
+    }
+}
+
+/// Renders an error in place of a report, so that the output stays valid JSON.
+pub fn error_json(error: &impl std::fmt::Display) -> String {
+    serde_json::to_string_pretty(&serde_json::json!({ "error": error.to_string() }))
+        .expect("Internal error: Error wasn't serialisable.")
+}
+
+#[derive(Serialize)]
+struct ReportJson {
+    request: &'static str,
+    years: Vec<YearJson>,
+}
+
+#[derive(Serialize)]
+struct YearJson {
+    year: Year,
+    days: Vec<DayJson>,
+}
+
+#[derive(Serialize)]
+struct DayJson {
+    day: Day,
+    parts: Vec<PartJson>,
+}
+
+#[derive(Serialize)]
+struct PartJson {
+    part: Part,
+    status: &'static str,
+    check: Option<CheckJson>,
+    run: Option<RunOutcomeJson>,
+    captured: String,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+enum CheckJson {
+    Passed {
+        expected: AnswerJson,
+        actual: AnswerJson,
+    },
+    Unchecked {
+        reason: &'static str,
+        actual: Option<AnswerJson>,
+        path: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        passed: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        unchecked: Option<usize>,
+    },
+    Failed {
+        reason: &'static str,
+        expected: AnswerJson,
+        actual: AnswerJson,
+        ordering: Option<&'static str>,
+    },
+    Cases {
+        cases: Vec<CaseJson>,
+    },
+    Implementations {
+        implementations: Vec<CaseJson>,
+    },
+    Fuzzed {
+        runs: usize,
+    },
+    FuzzDisagreed {
+        input: String,
+        saved_to: Option<String>,
+        answers: Vec<NamedAnswerJson>,
+    },
+}
+
+#[derive(Serialize)]
+struct CaseJson {
+    name: String,
+    check: CheckJson,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+enum RunOutcomeJson {
+    Ran(RunJson),
+    Disagreement { implementations: Vec<NamedRunJson> },
+    Error { error: String },
+}
+
+#[derive(Serialize)]
+struct RunJson {
+    answer: Option<AnswerJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_nanos: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_time_nanos: Option<u128>,
+    shared_time: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alternative_time_nanos: Option<Vec<NamedTimeJson>>,
+}
+
+#[derive(Serialize)]
+struct NamedRunJson {
+    name: String,
+    #[serde(flatten)]
+    run: RunJson,
+}
+
+#[derive(Serialize)]
+struct NamedTimeJson {
+    name: String,
+    time_nanos: Option<u128>, //Skipped alternatives have no time
+}
+
+#[derive(Serialize)]
+struct NamedAnswerJson {
+    name: String,
+    answer: Option<AnswerJson>,
+}
+
+#[derive(Serialize)]
+struct AnswerJson {
+    kind: &'static str,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    art: Option<String>,
+}
+
+fn year_json(year: Year, days: &[DayReturn], timings: bool) -> YearJson {
+    YearJson {
+        year,
+        days: (1..)
+            .zip(days)
+            .map(|(day, day_return)| day_json(day, day_return, timings))
+            .collect(),
+    }
+}
+
+fn day_json(day: Day, day_return: &DayReturn, timings: bool) -> DayJson {
+    DayJson {
+        day,
+        parts: (1..)
+            .zip(day_return)
+            .map(|(part, part_return)| part_json(part, part_return, timings))
+            .collect(),
+    }
+}
+
+fn part_json(part: Part, part_return: &PartReturn, timings: bool) -> PartJson {
+    let output = match part_return {
+        Err(AcquisitionError::NotMapped) => return empty_part(part, "not_mapped"),
+        Err(AcquisitionError::Unimplemented) => return empty_part(part, "unimplemented"),
+        Ok(output) => output,
+    };
+
+    let (check, run) = match &output.outcome {
+        PartOutcome::Checked(cr) => (Some(check_json(cr)), None),
+        PartOutcome::CheckedAndRan(crr) => match crr {
+            CheckedRunReturn::Ok { check, ret } => (
+                Some(check_json(check)),
+                Some(RunOutcomeJson::Ran(run_json(ret, timings))),
+            ),
+            CheckedRunReturn::Unchecked { reason, ret } => (
+                Some(unchecked_json(reason)),
+                Some(RunOutcomeJson::Ran(run_json(ret, timings))),
+            ),
+            CheckedRunReturn::CheckFailed(ce) => (Some(failed_json(ce)), None),
+            CheckedRunReturn::CasesFailed(cases) => (
+                Some(CheckJson::Cases {
+                    cases: cases_json(cases),
+                }),
+                None,
+            ),
+            CheckedRunReturn::Disagreement(implementations) => (
+                None,
+                Some(RunOutcomeJson::Disagreement {
+                    implementations: implementations
+                        .iter()
+                        .map(|(name, rr)| NamedRunJson {
+                            name: name.clone(),
+                            run: run_json(rr, timings),
+                        })
+                        .collect(),
+                }),
+            ),
+            CheckedRunReturn::RunFailed(ie) => (
+                None,
+                Some(RunOutcomeJson::Error {
+                    error: ie.to_string(),
+                }),
+            ),
+        },
+    };
+
+    PartJson {
+        part,
+        status: status(&output.outcome),
+        check,
+        run,
+        captured: output.captured.clone(),
+    }
+}
+
+fn empty_part(part: Part, status: &'static str) -> PartJson {
+    PartJson {
+        part,
+        status,
+        check: None,
+        run: None,
+        captured: String::new(),
+    }
+}
+
+/// Sums the outcome up in a word: Whether it failed, was verified, or neither.
+fn status(outcome: &PartOutcome) -> &'static str {
+    if outcome.failed() {
+        return "failed";
+    }
+    match outcome {
+        PartOutcome::Checked(cr) => match cr.clone().summarise() {
+            CheckReturn::Passed(..) | CheckReturn::Fuzzed(_) => "passed",
+            CheckReturn::Unchecked(Unchecked::Partial { .. }) => "partially_checked",
+            _ => "unchecked",
+        },
+        PartOutcome::CheckedAndRan(CheckedRunReturn::Ok { .. }) => "passed",
+        PartOutcome::CheckedAndRan(CheckedRunReturn::Unchecked {
+            reason: Unchecked::Partial { .. },
+            ..
+        }) => "partially_checked",
+        PartOutcome::CheckedAndRan(_) => "unchecked",
+    }
+}
+
+fn check_json(check_return: &CheckReturn) -> CheckJson {
+    match check_return {
+        CheckReturn::Passed(actual, expected) => CheckJson::Passed {
+            expected: answer_json(expected)
+                .expect("Internal error: Passed against an unimplemented answer."),
+            actual: answer_json(actual)
+                .expect("Internal error: Unimplemented answer passed a check."),
+        },
+        CheckReturn::Unchecked(reason) => unchecked_json(reason),
+        CheckReturn::Failed(ce) => failed_json(ce),
+        CheckReturn::Cases(cases) => CheckJson::Cases {
+            cases: cases_json(cases),
+        },
+        CheckReturn::Implementations(cases) => CheckJson::Implementations {
+            implementations: cases_json(cases),
+        },
+        CheckReturn::Fuzzed(FuzzReturn::Agreed { runs }) => CheckJson::Fuzzed { runs: *runs },
+        CheckReturn::Fuzzed(FuzzReturn::Disagreed {
+            input,
+            saved_to,
+            answers,
+        }) => CheckJson::FuzzDisagreed {
+            input: input.clone(),
+            saved_to: saved_to
+                .as_ref()
+                .ok()
+                .map(|path| path.display().to_string()),
+            answers: answers
+                .iter()
+                .map(|(name, answer)| NamedAnswerJson {
+                    name: name.clone(),
+                    answer: answer_json(answer),
+                })
+                .collect(),
+        },
+    }
+}
+
+fn cases_json(cases: &[CaseReturn]) -> Vec<CaseJson> {
+    cases
+        .iter()
+        .map(|case| CaseJson {
+            name: case.name.clone(),
+            check: check_json(&case.check_return),
+        })
+        .collect()
+}
+
+fn unchecked_json(reason: &Unchecked) -> CheckJson {
+    let (mut passed, mut unchecked) = (None, None);
+    let (reason, actual, path) = match reason {
+        Unchecked::Elided => ("elided", None, None),
+        Unchecked::ElideMismatch(sp) => ("elide_mismatch", answer_json(sp), None),
+        Unchecked::MissingInput => ("missing_input", None, None),
+        Unchecked::MissingOuput(sp) => ("missing_answer", answer_json(sp), None),
+        Unchecked::NothingToCompare => ("nothing_to_compare", None, None),
+        Unchecked::Unimplemented => ("unimplemented", None, None),
+        Unchecked::UnreadableInput(path) => {
+            ("unreadable_input", None, Some(path.display().to_string()))
+        }
+        Unchecked::Partial {
+            passed: p,
+            unchecked: u,
+        } => {
+            (passed, unchecked) = (Some(*p), Some(*u));
+            ("partially_checked", None, None)
+        }
+    };
+    CheckJson::Unchecked {
+        reason,
+        actual,
+        path,
+        passed,
+        unchecked,
+    }
+}
+
+fn failed_json(check_error: &CheckError) -> CheckJson {
+    let (reason, actual, expected, ordering) = match check_error {
+        CheckError::WrongFormat(actual, expected) => ("wrong_format", actual, expected, None),
+        CheckError::Incorrect(actual, expected, ordering) => (
+            "incorrect",
+            actual,
+            expected,
+            ordering.map(|ordering| match ordering {
+                std::cmp::Ordering::Less => "too_low",
+                std::cmp::Ordering::Equal => "equal",
+                std::cmp::Ordering::Greater => "too_high",
+            }),
+        ),
+    };
+    CheckJson::Failed {
+        reason,
+        expected: answer_json(expected)
+            .expect("Internal error: Failed against an unimplemented answer."),
+        actual: answer_json(actual).expect("Internal error: Unimplemented answer failed a check."),
+        ordering,
+    }
+}
+
+fn run_json(run_return: &RunReturn, timings: bool) -> RunJson {
+    RunJson {
+        answer: answer_json(&run_return.solution_part),
+        time_nanos: timings.then_some(run_return.time_taken.as_nanos()),
+        parse_time_nanos: run_return
+            .parse_time
+            .filter(|_| timings)
+            .map(|time| time.as_nanos()),
+        shared_time: run_return.shared_time,
+        alternative_time_nanos: timings.then(|| {
+            run_return
+                .alternative_times
+                .iter()
+                .map(|(name, time)| NamedTimeJson {
+                    name: name.clone(),
+                    time_nanos: time.map(|time| time.as_nanos()),
+                })
+                .collect()
+        }),
+    }
+}
+
+fn answer_json(solution_part: &SolutionPart) -> Option<AnswerJson> {
+    match solution_part {
+        SolutionPart::Unimplemented => None,
+        SolutionPart::Number(n) => Some(AnswerJson {
+            kind: "number",
+            value: n.to_string(),
+            art: None,
+        }),
+        SolutionPart::String(s) => Some(AnswerJson {
+            kind: "string",
+            value: s.clone(),
+            art: None,
+        }),
+        SolutionPart::Letters(art) => Some(AnswerJson {
+            kind: "letters",
+            value: art.letters().to_owned(),
+            art: Some(art.art().to_owned()),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        Report, Results, SolutionPart,
+        execution::{
+            Request,
+            result::{
+                CheckError, CheckReturn, CheckedRunReturn, PartOutcome, PartOutput, RunReturn,
+                Unchecked,
+            },
+        },
+    };
+
+    fn output(outcome: PartOutcome) -> PartOutput {
+        PartOutput {
+            outcome,
+            captured: String::new(),
+        }
+    }
+
+    #[test]
+    fn passed_and_failed_checks_share_a_schema() {
+        let passed = PartOutcome::CheckedAndRan(CheckedRunReturn::Ok {
+            check: Box::new(CheckReturn::Passed(
+                SolutionPart::from(11),
+                SolutionPart::from(11),
+            )),
+            ret: RunReturn {
+                solution_part: SolutionPart::from(1234),
+                time_taken: std::time::Duration::from_millis(5),
+                shared_time: false,
+                parse_time: None,
+                alternative_times: Vec::new(),
+            },
+        });
+        let failed = PartOutcome::Checked(CheckReturn::Failed(CheckError::Incorrect(
+            SolutionPart::from("ABC"),
+            SolutionPart::from("ABD"),
+            None,
+        )));
+        let report = Report::new(
+            Request::CheckAndRun,
+            Results::Day(2024, 1, [Ok(output(passed)), Ok(output(failed))]),
+        );
+
+        let rendered: serde_json::Value = serde_json::from_str(&report.to_json(false)).unwrap();
+        assert_eq!(
+            rendered,
+            json!({
+                "request": "run",
+                "years": [{
+                    "year": 2024,
+                    "days": [{
+                        "day": 1,
+                        "parts": [
+                            {
+                                "part": 1,
+                                "status": "passed",
+                                "check": {
+                                    "outcome": "passed",
+                                    "expected": { "kind": "number", "value": "11" },
+                                    "actual": { "kind": "number", "value": "11" },
+                                },
+                                "run": {
+                                    "outcome": "ran",
+                                    "answer": { "kind": "number", "value": "1234" },
+                                    "shared_time": false,
+                                },
+                                "captured": "",
+                            },
+                            {
+                                "part": 2,
+                                "status": "failed",
+                                "check": {
+                                    "outcome": "failed",
+                                    "reason": "incorrect",
+                                    "expected": { "kind": "string", "value": "ABD" },
+                                    "actual": { "kind": "string", "value": "ABC" },
+                                    "ordering": null,
+                                },
+                                "run": null,
+                                "captured": "",
+                            },
+                        ],
+                    }],
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn elided_runs_are_unchecked() {
+        let elided = PartOutcome::CheckedAndRan(CheckedRunReturn::Unchecked {
+            reason: Unchecked::Elided,
+            ret: RunReturn {
+                solution_part: SolutionPart::from(7),
+                time_taken: std::time::Duration::from_millis(1),
+                shared_time: false,
+                parse_time: None,
+                alternative_times: Vec::new(),
+            },
+        });
+        assert_eq!(super::status(&elided), "unchecked");
+    }
+}
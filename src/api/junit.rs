@@ -0,0 +1,410 @@
+use std::fmt::Write;
+
+use crate::{
+    ExitPolicy, Part, Report, Year,
+    api::display,
+    execution::result::{
+        AcquisitionError, CheckError, CheckReturn, CheckedRunReturn, FuzzReturn, PartOutcome,
+        PartReturn, Unchecked,
+    },
+};
+
+impl Report {
+    /// Renders the report as `JUnit` XML for CI systems, with one test suite per year and one test case per part.
+    ///
+    /// Passed checks pass, wrong answers and disagreeing implementations fail, and parts that couldn't be verified are skipped.
+    /// The failure type tells a wrong answer, failed test cases and disagreeing implementations apart.
+    /// Test inputs that couldn't be read are errors, as are unimplemented parts if the policy fails them. Parts without a solver are always skipped.
+    /// Parts that ran on the real input carry its run time.
+    pub fn to_junit(&self, policy: ExitPolicy) -> String {
+        let mut suites: Vec<(Year, Vec<Case>)> = Vec::new();
+        for (year, day, part, part_return) in self.parts() {
+            let case = Case {
+                classname: format!("{year}.day{day:02}"),
+                name: format!("part {part}"),
+                ..case(part, part_return, policy)
+            };
+            match suites.last_mut() {
+                Some((suite_year, cases)) if *suite_year == year => cases.push(case),
+                _ => suites.push((year, vec![case])),
+            }
+        }
+
+        let rendered: Vec<_> = suites
+            .iter()
+            .map(|(year, cases)| render_suite(&year.to_string(), cases))
+            .collect();
+        let all: Vec<_> = suites.iter().flat_map(|(_, cases)| cases).collect();
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"Advent of Code\"{}>\n{}</testsuites>\n",
+            counts(&all),
+            rendered.concat()
+        )
+    }
+}
+
+#[derive(Default)]
+struct Case {
+    classname: String,
+    name: String,
+    verdict: Verdict,
+    time: Option<std::time::Duration>,
+    captured: String,
+}
+
+#[derive(Default)]
+enum Verdict {
+    #[default]
+    Passed,
+    Failed(&'static str, String),
+    Errored(&'static str, String),
+    Skipped(String),
+}
+
+fn case(part: Part, part_return: &PartReturn, policy: ExitPolicy) -> Case {
+    let output = match part_return {
+        Err(AcquisitionError::NotMapped) => {
+            return Case {
+                verdict: Verdict::Skipped("No solution provided".to_owned()),
+                ..Case::default()
+            };
+        }
+        Err(AcquisitionError::Unimplemented) => {
+            let message = format!("Part {part} is unimplemented");
+            return Case {
+                verdict: if policy.unimplemented_fails {
+                    Verdict::Errored("Unimplemented", message)
+                } else {
+                    Verdict::Skipped(message)
+                },
+                ..Case::default()
+            };
+        }
+        Ok(output) => output,
+    };
+
+    let (verdict, time) = match &output.outcome {
+        PartOutcome::Checked(cr) => (check_verdict(cr), None),
+        PartOutcome::CheckedAndRan(crr) => {
+            let time = crr.run_return().map(|rr| rr.time_taken);
+            let verdict = match crr {
+                CheckedRunReturn::Ok { .. } => Verdict::Passed,
+                CheckedRunReturn::Unchecked { reason, .. } => unchecked_verdict(reason),
+                CheckedRunReturn::CheckFailed(ce) => {
+                    Verdict::Failed(check_error_type(ce), plain(ce))
+                }
+                CheckedRunReturn::CasesFailed(_) => Verdict::Failed("CasesFailed", plain(crr)),
+                CheckedRunReturn::Disagreement(_) => Verdict::Failed("Disagreement", plain(crr)),
+                CheckedRunReturn::RunFailed(ie) => Verdict::Errored("InputError", ie.to_string()),
+            };
+            (verdict, time)
+        }
+    };
+
+    Case {
+        verdict,
+        time,
+        captured: output.captured.clone(),
+        ..Case::default()
+    }
+}
+
+fn check_verdict(check_return: &CheckReturn) -> Verdict {
+    match check_return {
+        CheckReturn::Passed(..) | CheckReturn::Fuzzed(FuzzReturn::Agreed { .. }) => Verdict::Passed,
+        CheckReturn::Unchecked(reason) => unchecked_verdict(reason),
+        CheckReturn::Failed(ce) => Verdict::Failed(check_error_type(ce), plain(ce)),
+        CheckReturn::Fuzzed(FuzzReturn::Disagreed { .. }) => {
+            Verdict::Failed("Disagreement", plain(check_return))
+        }
+        CheckReturn::Cases(_) if check_return.failed() => {
+            Verdict::Failed("CasesFailed", plain(check_return))
+        }
+        CheckReturn::Implementations(_) if check_return.failed() => {
+            Verdict::Failed("ImplementationsFailed", plain(check_return))
+        }
+        //? An error in any case outweighs the others, same as for the exit code
+        CheckReturn::Cases(cases) | CheckReturn::Implementations(cases) => cases
+            .iter()
+            .map(|case| check_verdict(&case.check_return))
+            .find(|verdict| matches!(verdict, Verdict::Errored(..)))
+            .unwrap_or_else(|| match check_return.clone().summarise() {
+                CheckReturn::Passed(..) => Verdict::Passed,
+                summary => Verdict::Skipped(plain(&summary)),
+            }),
+    }
+}
+
+fn unchecked_verdict(reason: &Unchecked) -> Verdict {
+    match reason {
+        Unchecked::UnreadableInput(_) => Verdict::Errored("UnreadableInput", plain(reason)),
+        reason => Verdict::Skipped(plain(reason)),
+    }
+}
+
+fn check_error_type(check_error: &CheckError) -> &'static str {
+    match check_error {
+        CheckError::Incorrect(..) => "Incorrect",
+        CheckError::WrongFormat(..) => "WrongFormat",
+    }
+}
+
+/// The terminal rendering of a result, without its styling.
+fn plain(result: &impl std::fmt::Display) -> String {
+    display::strip_styles(&result.to_string())
+}
+
+fn render_suite(name: &str, cases: &[Case]) -> String {
+    let rendered: String = cases.iter().map(render_case).collect();
+    format!(
+        "  <testsuite name=\"{}\"{}>\n{rendered}  </testsuite>\n",
+        escape(name),
+        counts(&cases.iter().collect::<Vec<_>>())
+    )
+}
+
+fn render_case(case: &Case) -> String {
+    let time = case
+        .time
+        .map(|time| format!(" time=\"{:.6}\"", time.as_secs_f64()))
+        .unwrap_or_default();
+    let mut body = match &case.verdict {
+        Verdict::Passed => String::new(),
+        Verdict::Failed(kind, message) => format!(
+            "      <failure type=\"{kind}\" message=\"{}\">{}</failure>\n",
+            escape(first_line(message)),
+            escape(message)
+        ),
+        Verdict::Errored(kind, message) => format!(
+            "      <error type=\"{kind}\" message=\"{}\">{}</error>\n",
+            escape(first_line(message)),
+            escape(message)
+        ),
+        Verdict::Skipped(message) => format!("      <skipped message=\"{}\"/>\n", escape(message)),
+    };
+    if !case.captured.is_empty() {
+        let _ = writeln!(
+            body,
+            "      <system-out>{}</system-out>",
+            escape(&display::strip_styles(&case.captured))
+        );
+    }
+
+    let opening = format!(
+        "    <testcase classname=\"{}\" name=\"{}\"{time}",
+        escape(&case.classname),
+        escape(&case.name)
+    );
+    if body.is_empty() {
+        format!("{opening}/>\n")
+    } else {
+        format!("{opening}>\n{body}    </testcase>\n")
+    }
+}
+
+/// The count and time attributes of a suite.
+fn counts(cases: &[&Case]) -> String {
+    let count =
+        |matches: fn(&Verdict) -> bool| cases.iter().filter(|case| matches(&case.verdict)).count();
+    let time: std::time::Duration = cases.iter().filter_map(|case| case.time).sum();
+    format!(
+        " tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.6}\"",
+        cases.len(),
+        count(|verdict| matches!(verdict, Verdict::Failed(..))),
+        count(|verdict| matches!(verdict, Verdict::Errored(..))),
+        count(|verdict| matches!(verdict, Verdict::Skipped(_))),
+        time.as_secs_f64()
+    )
+}
+
+fn first_line(message: &str) -> &str {
+    message.lines().next().unwrap_or_default()
+}
+
+/// Escapes text for attributes and element content alike, dropping control characters XML doesn't allow.
+fn escape(text: &str) -> String {
+    text.chars()
+        .filter(|&c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'))
+        .fold(String::with_capacity(text.len()), |mut escaped, c| {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                c => escaped.push(c),
+            }
+            escaped
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{case, escape, render_case};
+    use crate::{
+        ExitPolicy, SolutionPart,
+        execution::result::{
+            AcquisitionError, CaseReturn, CheckError, CheckReturn, CheckedRunReturn, PartOutcome,
+            PartOutput, PartReturn, RunReturn, Unchecked,
+        },
+    };
+
+    fn rendered(part_return: &PartReturn, policy: ExitPolicy) -> String {
+        render_case(&case(1, part_return, policy))
+    }
+
+    fn rendered_outcome(outcome: PartOutcome) -> String {
+        rendered(
+            &Ok(PartOutput {
+                outcome,
+                captured: String::new(),
+            }),
+            ExitPolicy::default(),
+        )
+    }
+
+    fn cases(check_returns: impl IntoIterator<Item = CheckReturn>) -> Vec<CaseReturn> {
+        check_returns
+            .into_iter()
+            .enumerate()
+            .map(|(i, check_return)| CaseReturn {
+                name: format!("case {i}"),
+                check_return,
+            })
+            .collect()
+    }
+
+    fn passed() -> CheckReturn {
+        CheckReturn::Passed(SolutionPart::from(42), SolutionPart::from(42))
+    }
+
+    fn incorrect() -> CheckReturn {
+        CheckReturn::Failed(CheckError::Incorrect(
+            SolutionPart::from(41),
+            SolutionPart::from(42),
+            Some(std::cmp::Ordering::Less),
+        ))
+    }
+
+    fn run_return() -> RunReturn {
+        RunReturn {
+            solution_part: SolutionPart::from(1234),
+            time_taken: std::time::Duration::from_millis(5),
+            shared_time: false,
+            parse_time: None,
+            alternative_times: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn escape_covers_markup_and_drops_control_characters() {
+        assert_eq!(
+            escape("<a href=\"x\">Tom & 'Jerry'</a>\u{7}\n"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; &apos;Jerry&apos;&lt;/a&gt;\n"
+        );
+    }
+
+    #[test]
+    fn captured_output_is_unstyled_and_escaped() {
+        let part_return = Ok(PartOutput {
+            outcome: PartOutcome::Checked(passed()),
+            captured: "\u{1b}[1m<grid>\u{1b}[22m & more".to_owned(),
+        });
+        assert!(
+            rendered(&part_return, ExitPolicy::default())
+                .contains("<system-out>&lt;grid&gt; &amp; more</system-out>")
+        );
+    }
+
+    #[test]
+    fn failure_types_tell_failures_apart() {
+        let expectations = [
+            (PartOutcome::Checked(incorrect()), "Incorrect"),
+            (
+                PartOutcome::Checked(CheckReturn::Failed(CheckError::WrongFormat(
+                    SolutionPart::from(1),
+                    SolutionPart::from("one"),
+                ))),
+                "WrongFormat",
+            ),
+            (
+                PartOutcome::Checked(CheckReturn::Cases(cases([passed(), incorrect()]))),
+                "CasesFailed",
+            ),
+            (
+                PartOutcome::Checked(CheckReturn::Implementations(cases([passed(), incorrect()]))),
+                "ImplementationsFailed",
+            ),
+            (
+                PartOutcome::CheckedAndRan(CheckedRunReturn::CasesFailed(cases([incorrect()]))),
+                "CasesFailed",
+            ),
+            (
+                PartOutcome::CheckedAndRan(CheckedRunReturn::Disagreement(vec![
+                    ("Primary".to_owned(), run_return()),
+                    ("Naive".to_owned(), run_return()),
+                ])),
+                "Disagreement",
+            ),
+        ];
+        for (outcome, kind) in expectations {
+            let rendered = rendered_outcome(outcome);
+            assert!(
+                rendered.contains(&format!("<failure type=\"{kind}\"")),
+                "Expected a {kind} failure in {rendered}"
+            );
+        }
+    }
+
+    #[test]
+    fn unreadable_input_is_an_error() {
+        let unreadable = || CheckReturn::Unchecked(Unchecked::UnreadableInput("day01.txt".into()));
+        for outcome in [
+            PartOutcome::Checked(unreadable()),
+            PartOutcome::Checked(CheckReturn::Cases(cases([passed(), unreadable()]))),
+            PartOutcome::CheckedAndRan(CheckedRunReturn::Unchecked {
+                reason: Unchecked::UnreadableInput("day01.txt".into()),
+                ret: run_return(),
+            }),
+        ] {
+            let rendered = rendered_outcome(outcome);
+            assert!(
+                rendered.contains("<error type=\"UnreadableInput\""),
+                "Expected an error in {rendered}"
+            );
+        }
+    }
+
+    #[test]
+    fn passes_and_skips() {
+        let passing = rendered_outcome(PartOutcome::CheckedAndRan(CheckedRunReturn::Ok {
+            check: Box::new(CheckReturn::Cases(cases([passed(), passed()]))),
+            ret: run_return(),
+        }));
+        assert!(passing.ends_with("time=\"0.005000\"/>\n"), "{passing}");
+
+        for outcome in [
+            PartOutcome::Checked(CheckReturn::Unchecked(Unchecked::MissingInput)),
+            PartOutcome::Checked(CheckReturn::Cases(cases([
+                passed(),
+                CheckReturn::Unchecked(Unchecked::MissingInput),
+            ]))),
+        ] {
+            assert!(rendered_outcome(outcome).contains("<skipped message="));
+        }
+        assert!(
+            rendered(&Err(AcquisitionError::NotMapped), ExitPolicy::strict())
+                .contains("<skipped message=\"No solution provided\"/>")
+        );
+    }
+
+    #[test]
+    fn unimplemented_parts_follow_the_policy() {
+        let unimplemented = Err(AcquisitionError::Unimplemented);
+        assert!(rendered(&unimplemented, ExitPolicy::default()).contains("<skipped"));
+        assert!(
+            rendered(&unimplemented, ExitPolicy::strict())
+                .contains("<error type=\"Unimplemented\"")
+        );
+    }
+}
@@ -0,0 +1,424 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    Day, Report, Results, SolutionPart, Year,
+    api::display::style::{self, Speed},
+    execution::result::{
+        AcquisitionError, CheckReturn, CheckedRunReturn, DayReturn, FuzzReturn, PartOutcome,
+        PartReturn,
+    },
+};
+
+/// Renders results as Markdown tables, for keeping a README of solutions up to date.
+///
+/// A single year becomes a table of days with the star, answer and run time of each part,
+/// multiple years become a matrix of days against years with the stars of each day.
+/// Only parts whose tests passed earn a star, parts that failed are marked ❌ and parts that couldn't be verified ❔, which includes parts whose tests were elided.
+///
+/// ```no_run
+/// use aoc_handler::{Day, Handler, Markdown, Solver, Year};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mapper = |_year: Year, _day: Day| -> Option<Solver> { None };
+///     let report = Handler::new(&mapper).reports().run_year(2024)?;
+///
+///     let table = Markdown::default().with_redacted_answers(true).render(&report);
+///     Markdown::update_section("README.md", "2024", &table)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Markdown {
+    redact_answers: bool,
+    timing_badges: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SectionError {
+    #[error("Unable to read {0}. {1}")]
+    Read(PathBuf, #[source] std::io::Error),
+    #[error("{0} has no section marked <!-- {1} --> … <!-- /{1} -->")]
+    MissingMarkers(PathBuf, String),
+    #[error("Unable to write {0}. {1}")]
+    Write(PathBuf, #[source] std::io::Error),
+}
+
+impl Markdown {
+    /// Leaves the answers out, for public repositories that shouldn't spoil them.
+    #[must_use]
+    pub const fn with_redacted_answers(mut self, redact_answers: bool) -> Self {
+        self.redact_answers = redact_answers;
+        self
+    }
+
+    /// Shows run times as badges coloured by the same thresholds as the terminal output, rather than as plain text.
+    /// Matrices then also show the total run time of each day.
+    #[must_use]
+    pub const fn with_timing_badges(mut self, timing_badges: bool) -> Self {
+        self.timing_badges = timing_badges;
+        self
+    }
+
+    /// Renders a report at its own granularity: Multiple years as a matrix, anything else as a year table.
+    pub fn render(self, report: &Report) -> String {
+        match report.results() {
+            Results::Years(years) => self.render_years(years),
+            Results::Year(_, days) => self.render_year(days),
+            Results::Day(_, day, day_return) => self.table([(*day, day_return.clone())]),
+            Results::Part(_, day, part, part_return) => {
+                let mut day_return = [
+                    Err(AcquisitionError::NotMapped),
+                    Err(AcquisitionError::NotMapped),
+                ];
+                day_return[usize::from(*part) - 1].clone_from(part_return);
+                self.table([(*day, day_return)])
+            }
+        }
+    }
+
+    /// A table of the days of a year, one row per day with a solver. Days are in order, starting at day 1.
+    pub fn render_year(self, days: &[DayReturn]) -> String {
+        self.table((1..).zip(days.iter().cloned()))
+    }
+
+    /// A matrix of days against years, with the stars of each day. Days without a solver in any year are left out.
+    pub fn render_years(self, years: &[(Year, Vec<DayReturn>)]) -> String {
+        let day_count = years.iter().map(|(_, days)| days.len()).max().unwrap_or(0);
+        let header: Vec<String> = years.iter().map(|(year, _)| year.to_string()).collect();
+        let rows = (0..day_count).filter_map(|index| {
+            let cells: Vec<String> = years
+                .iter()
+                .map(|(_, days)| {
+                    days.get(index)
+                        .map(|day_return| self.day_cell(day_return))
+                        .unwrap_or_default()
+                })
+                .collect();
+            //? Days no year has a solver for are left out, like in year tables
+            (!cells.iter().all(String::is_empty))
+                .then(|| row(std::iter::once((index + 1).to_string()).chain(cells)))
+        });
+
+        std::iter::once(row(std::iter::once("Day".to_owned()).chain(header)))
+            .chain(std::iter::once(format!(
+                "|----:|{}",
+                ":---:|".repeat(years.len())
+            )))
+            .chain(rows)
+            .map(|line| line + "\n")
+            .collect()
+    }
+
+    /// Replaces everything between `<!-- {section} -->` and `<!-- /{section} -->` in a file, leaving the rest of it untouched.
+    /// The file is only written if the section changed, which is returned.
+    pub fn update_section(
+        path: impl AsRef<Path>,
+        section: &str,
+        content: &str,
+    ) -> Result<bool, SectionError> {
+        let path = path.as_ref();
+        let file =
+            std::fs::read_to_string(path).map_err(|e| SectionError::Read(path.to_owned(), e))?;
+        let (start, end) = (
+            format!("<!-- {section} -->"),
+            format!("<!-- /{section} -->"),
+        );
+        let missing = || SectionError::MissingMarkers(path.to_owned(), section.to_owned());
+
+        let content_start = file.find(&start).ok_or_else(missing)? + start.len();
+        let content_end = file[content_start..].find(&end).ok_or_else(missing)? + content_start;
+        let updated = format!(
+            "{}\n{}\n{}",
+            &file[..content_start],
+            content.trim_end(),
+            &file[content_end..]
+        );
+
+        if updated == file {
+            return Ok(false);
+        }
+        std::fs::write(path, updated).map_err(|e| SectionError::Write(path.to_owned(), e))?;
+        Ok(true)
+    }
+
+    fn table(self, days: impl IntoIterator<Item = (Day, DayReturn)>) -> String {
+        let rows = days
+            .into_iter()
+            .filter(|(_, day_return)| {
+                !matches!(
+                    day_return,
+                    [
+                        Err(AcquisitionError::NotMapped),
+                        Err(AcquisitionError::NotMapped)
+                    ]
+                )
+            })
+            .map(|(day, [p1, p2])| {
+                row([day.to_string(), self.part_cell(&p1), self.part_cell(&p2)].into_iter())
+            });
+
+        [
+            "| Day | Part 1 | Part 2 |".to_owned(),
+            "|----:|:-------|:-------|".to_owned(),
+        ]
+        .into_iter()
+        .chain(rows)
+        .map(|line| line + "\n")
+        .collect()
+    }
+
+    fn part_cell(self, part_return: &PartReturn) -> String {
+        let output = match part_return {
+            Err(AcquisitionError::NotMapped) => return String::new(),
+            Err(AcquisitionError::Unimplemented) => return "–".to_owned(),
+            Ok(output) => output,
+        };
+        let mut cell = vec![star(&output.outcome).to_owned()];
+        if let Some(run_return) = output.outcome.run_return() {
+            if !self.redact_answers
+                && !matches!(run_return.solution_part, SolutionPart::Unimplemented)
+            {
+                cell.push(format!(
+                    "`{}`",
+                    escape(&run_return.solution_part.to_string())
+                ));
+            }
+            cell.push(self.time(run_return.time_taken));
+        }
+        cell.join(" ")
+    }
+
+    fn day_cell(self, day_return: &DayReturn) -> String {
+        let stars: String = day_return
+            .iter()
+            .filter_map(|part_return| part_return.as_ref().ok())
+            .map(|output| star(&output.outcome))
+            .collect();
+        let times: Vec<Duration> = day_return
+            .iter()
+            .filter_map(|part_return| part_return.as_ref().ok()?.outcome.run_return())
+            .map(|run_return| run_return.time_taken)
+            .collect();
+        if self.timing_badges && !times.is_empty() {
+            format!("{stars} {}", self.time(times.iter().sum()))
+        } else {
+            stars
+        }
+    }
+
+    fn time(self, time: Duration) -> String {
+        let text = format!("{time:.2?}");
+        if !self.timing_badges {
+            return text;
+        }
+        let colour = match style::speed(time) {
+            Speed::Fast => "brightgreen",
+            Speed::Medium => "yellow",
+            Speed::Slow => "red",
+        };
+        format!(
+            "![{text}](https://img.shields.io/badge/{}-{colour})",
+            badge_text(&text)
+        )
+    }
+}
+
+fn row(cells: impl Iterator<Item = String>) -> String {
+    cells.fold("|".to_owned(), |row, cell| row + " " + &cell + " |")
+}
+
+/// ⭐ for a part whose tests passed, ❌ for one that failed, and ❔ for one that couldn't be verified.
+fn star(outcome: &PartOutcome) -> &'static str {
+    if outcome.failed() {
+        return "❌";
+    }
+    let verified = match outcome {
+        PartOutcome::Checked(cr) => matches!(
+            cr.clone().summarise(),
+            CheckReturn::Passed(..) | CheckReturn::Fuzzed(FuzzReturn::Agreed { .. })
+        ),
+        //? Elided tests verify nothing, so an elided run is unchecked, same as in the JSON status
+        PartOutcome::CheckedAndRan(crr) => matches!(crr, CheckedRunReturn::Ok { .. }),
+    };
+    if verified { "⭐" } else { "❔" }
+}
+
+/// Keeps answers on one line and out of the table's structure.
+fn escape(answer: &str) -> String {
+    answer.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+/// Shields.io reads dashes and underscores as separators, and everything else has to be a valid URL path.
+fn badge_text(text: &str) -> String {
+    text.chars().fold(String::new(), |mut encoded, c| {
+        match c {
+            '-' => encoded.push_str("--"),
+            '_' => encoded.push_str("__"),
+            ' ' => encoded.push('_'),
+            c if c.is_ascii_alphanumeric() || c == '.' => encoded.push(c),
+            c => {
+                for byte in c.encode_utf8(&mut [0; 4]).bytes() {
+                    let _ = write!(encoded, "%{byte:02X}");
+                }
+            }
+        }
+        encoded
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Markdown, SectionError, badge_text, escape};
+    use crate::{
+        SolutionPart,
+        execution::result::{
+            AcquisitionError, CheckError, CheckReturn, CheckedRunReturn, DayReturn, PartOutcome,
+            PartOutput, RunReturn, Unchecked,
+        },
+    };
+
+    fn output(outcome: PartOutcome) -> PartOutput {
+        PartOutput {
+            outcome,
+            captured: String::new(),
+        }
+    }
+
+    fn ran(answer: impl Into<SolutionPart>, millis: u64) -> PartOutput {
+        output(PartOutcome::CheckedAndRan(CheckedRunReturn::Ok {
+            check: Box::new(CheckReturn::Passed(
+                SolutionPart::from(1),
+                SolutionPart::from(1),
+            )),
+            ret: run_return(answer, millis),
+        }))
+    }
+
+    fn run_return(answer: impl Into<SolutionPart>, millis: u64) -> RunReturn {
+        RunReturn {
+            solution_part: answer.into(),
+            time_taken: Duration::from_millis(millis),
+            shared_time: false,
+            parse_time: None,
+            alternative_times: Vec::new(),
+        }
+    }
+
+    fn failed() -> PartOutput {
+        output(PartOutcome::Checked(CheckReturn::Failed(
+            CheckError::Incorrect(SolutionPart::from(1), SolutionPart::from(2), None),
+        )))
+    }
+
+    fn not_mapped() -> DayReturn {
+        [
+            Err(AcquisitionError::NotMapped),
+            Err(AcquisitionError::NotMapped),
+        ]
+    }
+
+    fn year() -> Vec<DayReturn> {
+        vec![
+            [Ok(ran(1234, 5)), Ok(ran("a|b", 20))],
+            not_mapped(),
+            [Ok(failed()), Err(AcquisitionError::Unimplemented)],
+        ]
+    }
+
+    #[test]
+    fn year_tables_leave_out_days_without_a_solver() {
+        assert_eq!(
+            Markdown::default().render_year(&year()),
+            "| Day | Part 1 | Part 2 |\n\
+             |----:|:-------|:-------|\n\
+             | 1 | ⭐ `1234` 5.00ms | ⭐ `a\\|b` 20.00ms |\n\
+             | 3 | ❌ | – |\n"
+        );
+    }
+
+    #[test]
+    fn elided_runs_are_unverified() {
+        let elided = output(PartOutcome::CheckedAndRan(CheckedRunReturn::Unchecked {
+            reason: Unchecked::Elided,
+            ret: run_return(7, 1),
+        }));
+        assert!(
+            Markdown::default()
+                .render_year(&[[Ok(elided), Err(AcquisitionError::NotMapped)]])
+                .contains("| 1 | ❔ `7` 1.00ms |  |")
+        );
+    }
+
+    #[test]
+    fn redacted_answers_keep_stars_and_times() {
+        assert_eq!(
+            Markdown::default()
+                .with_redacted_answers(true)
+                .render_year(&year()),
+            "| Day | Part 1 | Part 2 |\n\
+             |----:|:-------|:-------|\n\
+             | 1 | ⭐ 5.00ms | ⭐ 20.00ms |\n\
+             | 3 | ❌ | – |\n"
+        );
+    }
+
+    #[test]
+    fn matrices_show_the_stars_of_each_day_and_year() {
+        let years = [
+            (2015, year()),
+            (2016, vec![[Ok(failed()), Ok(ran(1, 1))], not_mapped()]),
+        ];
+        assert_eq!(
+            Markdown::default().render_years(&years),
+            "| Day | 2015 | 2016 |\n\
+             |----:|:---:|:---:|\n\
+             | 1 | ⭐⭐ | ❌⭐ |\n\
+             | 3 | ❌ |  |\n"
+        );
+    }
+
+    #[test]
+    fn answers_stay_on_one_line_and_inside_their_cell() {
+        assert_eq!(escape("a|b\nc\r\nd"), "a\\|b c  d");
+    }
+
+    #[test]
+    fn badge_text_escapes_separators_and_encodes_the_rest() {
+        assert_eq!(badge_text("12.50µs"), "12.50%C2%B5s");
+        assert_eq!(badge_text("a-b_c d"), "a--b__c_d");
+    }
+
+    #[test]
+    fn update_section_only_touches_the_section() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("README.md");
+        let before =
+            "# Solutions\r\n\n<!-- 2024 -->\nold table\n<!-- /2024 -->\n  trailing text\r\n";
+        std::fs::write(&path, before).unwrap();
+
+        assert!(Markdown::update_section(&path, "2024", "new table\n").unwrap());
+        let after = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            after,
+            "# Solutions\r\n\n<!-- 2024 -->\nnew table\n<!-- /2024 -->\n  trailing text\r\n"
+        );
+
+        assert!(!Markdown::update_section(&path, "2024", "new table\n").unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), after);
+
+        assert!(matches!(
+            Markdown::update_section(&path, "2023", "new table"),
+            Err(SectionError::MissingMarkers(_, section)) if section == "2023"
+        ));
+        assert!(matches!(
+            Markdown::update_section(dir.path().join("missing.md"), "2024", "new table"),
+            Err(SectionError::Read(..))
+        ));
+    }
+}
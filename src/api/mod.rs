@@ -1,10 +1,22 @@
+use std::io::IsTerminal;
+
 use crate::{
-    Day, Handler, Part, Year,
-    execution::Request,
+    Day, Handler, HandlerError, Part, SolutionPart, Year,
+    execution::{Request, result::DayReturn},
+    input_handler::CustomInput,
     time_key::{TimeDetailDay, TimeDetailDayAndPart, TimeDetailNone, TimeKey},
 };
 
 pub mod display;
+mod exit_code;
+pub use exit_code::{ExitPolicy, Failure};
+#[cfg(feature = "json")]
+mod json;
+mod junit;
+mod markdown;
+pub use markdown::{Markdown, SectionError};
+mod report;
+pub use report::{Report, Results};
 pub mod traits;
 
 impl Handler<'_> {
@@ -44,6 +56,73 @@ impl Handler<'_> {
         self.process_part(Request::CheckAndRun, year, day, part);
     }
 
+    /// Runs a specific part of a specific day on the given input, instead of the real Advent of Code input. Tests are skipped.
+    /// If `expected` is anything but unit `()`, the result is checked against it.
+    pub fn run_part_on_input(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        input: &str,
+        expected: impl Into<SolutionPart>,
+    ) {
+        self.process_part_on(
+            CustomInput::Text(input.to_owned()),
+            year,
+            day,
+            part,
+            &expected.into(),
+        );
+    }
+
+    /// Runs a specific part of a specific day on the contents of the given file, instead of the real Advent of Code input. Tests are skipped.
+    /// If `expected` is anything but unit `()`, the result is checked against it.
+    pub fn run_part_on_file(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        path: impl AsRef<std::path::Path>,
+        expected: impl Into<SolutionPart>,
+    ) {
+        self.process_part_on(
+            CustomInput::File(path.as_ref().to_owned()),
+            year,
+            day,
+            part,
+            &expected.into(),
+        );
+    }
+
+    /// Runs a specific part of a specific day on input read from stdin, instead of the real Advent of Code input. Tests are skipped.
+    /// If `expected` is anything but unit `()`, the result is checked against it.
+    pub fn run_part_on_stdin(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        expected: impl Into<SolutionPart>,
+    ) {
+        self.process_part_on(CustomInput::Stdin, year, day, part, &expected.into());
+    }
+
+    /// Compares all implementations of a specific part on inputs produced by `generator`, called with a seed and a size.
+    /// Sizes grow from 1 to `max_size` over the `runs`. The first input the implementations disagree on is shrunk, saved, and reported.
+    pub fn fuzz_part(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        generator: impl Fn(u64, usize) -> String,
+        runs: usize,
+        max_size: usize,
+    ) {
+        self.print_report(
+            self.reports()
+                .fuzz_part(year, day, part, generator, runs, max_size),
+        );
+    }
+
     /// Checks all provided years, after deduplicating and ordering them for bulk validation/benchmarking.
     /// Checking attempts to run the provided `test_input()` and compare it to `test_answer()`.
     pub fn check_year_range(&self, years: impl Iterator<Item = Year>) {
@@ -80,109 +159,422 @@ impl Handler<'_> {
         self.process_part(Request::Check, year, day, part);
     }
 
-    fn process_year_range(&self, request: Request, years: impl Iterator<Item = Year>) {
-        let (years, errors): (std::collections::BTreeSet<_>, std::collections::HashSet<_>) =
-            itertools::Itertools::partition_result(years.map(TimeKey::<TimeDetailNone>::new));
+    /// The `check_*` and `run_*` methods, returning a [`Report`] instead of printing it.
+    pub fn reports(&self) -> Reports<'_> {
+        Reports { handler: self }
+    }
 
-        if !errors.is_empty() {
-            eprint!("Provided invalid years:");
-            for tke in errors {
-                eprintln!("{tke}");
+    /// Prints a report in the chosen [`OutputFormat`], or the error that prevented it.
+    pub(crate) fn print_report(&self, report: Result<Report, HandlerError>) {
+        match (report, self.format) {
+            (Ok(report), OutputFormat::Text) => println!("{}", report.render()),
+            (Err(he), OutputFormat::Text) => eprintln!("{he}"),
+            #[cfg(feature = "json")]
+            (Ok(report), OutputFormat::Json { timings }) => println!("{}", report.to_json(timings)),
+            #[cfg(feature = "json")]
+            (Err(he), OutputFormat::Json { .. }) => println!("{}", json::error_json(&he)),
+            (Ok(report), OutputFormat::JUnit { policy }) => println!("{}", report.to_junit(policy)),
+            (Ok(report), OutputFormat::Markdown(markdown)) => {
+                print!("{}", markdown.render(&report));
             }
+            (Err(he), OutputFormat::JUnit { .. } | OutputFormat::Markdown(_)) => eprintln!("{he}"),
         }
-        if years.is_empty() {
-            eprintln!("All provided years were invalid.");
-            std::process::exit(0);
-        }
+    }
 
-        let year_returns = self.execute_year_range(request, years.into_iter());
+    /// Years are only shown as they complete as text on a terminal, and only if the theme allows the escape sequences redrawing them takes.
+    pub(crate) fn streams(&self) -> bool {
+        matches!(self.format, OutputFormat::Text)
+            && std::io::stdout().is_terminal()
+            && display::Theme::current().is_styled()
+    }
 
-        println!(
-            "{} {}",
-            request,
-            display::render_multiple_year_results(year_returns)
-        );
+    fn process_year_range(&self, request: Request, years: impl Iterator<Item = Year>) {
+        if self.streams() {
+            print!("{request} ");
+            match valid_years(years) {
+                Ok(years) => {
+                    for time_key in years {
+                        self.stream_year(request, time_key);
+                    }
+                }
+                Err(he) => eprintln!("{he}"),
+            }
+        } else {
+            self.print_report(self.reports().year_range(request, years));
+        }
     }
 
     fn process_year(&self, request: Request, year: Year) {
-        let time_key = TimeKey::<TimeDetailNone>::new(year).unwrap_or_else(|tke| {
-            eprintln!("{tke}");
-            std::process::exit(0)
-        });
-
-        let day_returns = self.execute_year(request, time_key);
+        if self.streams() {
+            match TimeKey::<TimeDetailNone>::new(year) {
+                Ok(time_key) => {
+                    print!("{request} ");
+                    self.stream_year(request, time_key);
+                }
+                Err(tke) => eprintln!("{tke}"),
+            }
+        } else {
+            self.print_report(self.reports().year(request, year));
+        }
+    }
 
-        println!(
-            "{} {}",
-            request,
-            display::render_year_returns(time_key, day_returns)
-        );
+    //? Years take a while, so on a terminal the days are shown as they complete
+    pub(crate) fn stream_year(
+        &self,
+        request: Request,
+        time_key: TimeKey<TimeDetailNone>,
+    ) -> Vec<DayReturn> {
+        let mut live = display::LiveYear::start(time_key);
+        let day_returns = time_key
+            .iterate()
+            .map(|key| {
+                let day_return = self.execute_day_observed(request, key, |key| live.status(key));
+                live.push(key, day_return.clone());
+                day_return
+            })
+            .collect();
+        live.finish();
+        day_returns
     }
 
     fn process_most_recent_day(&self, request: Request, year: Year) {
-        let time_key = TimeKey::<TimeDetailNone>::new(year).unwrap_or_else(|tke| {
-            eprintln!("{tke}");
-            std::process::exit(0)
-        });
+        self.print_report(self.reports().most_recent_day(request, year));
+    }
 
-        let (day, day_return) = self.execute_most_recent_day(request, time_key);
+    fn process_day(&self, request: Request, year: Year, day: Day) {
+        self.print_report(self.reports().day(request, year, day));
+    }
 
-        let time_key = TimeKey::<TimeDetailDay>::new(year, day)
-            .expect("Internal error: Most recent day was invalid.");
+    fn process_most_recent_part(&self, request: Request, year: Year) {
+        self.print_report(self.reports().most_recent_part(request, year));
+    }
 
-        println!(
-            "{} {}",
-            request,
-            display::render_day_return(time_key, &day_return)
-        );
+    fn process_part(&self, request: Request, year: Year, day: Day, part: Part) {
+        self.print_report(self.reports().part(request, year, day, part));
     }
 
-    fn process_day(&self, request: Request, year: Year, day: Day) {
-        let time_key = TimeKey::<TimeDetailDay>::new(year, day).unwrap_or_else(|tke| {
-            eprintln!("{tke}");
-            std::process::exit(0)
-        });
+    fn process_part_on(
+        &self,
+        input: CustomInput,
+        year: Year,
+        day: Day,
+        part: Part,
+        expected: &SolutionPart,
+    ) {
+        self.print_report(self.reports().part_on(input, year, day, part, expected));
+    }
+}
 
-        let day_return = self.execute_day(request, time_key);
+/// How the printing `check_*` and `run_*` methods show their results, chosen through [`Handler::with_output_format`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Coloured tables, shown day by day on a terminal.
+    #[default]
+    Text,
+    /// The full result tree as JSON, see [`Report::to_json`]. Requires the `json` feature.
+    #[cfg(feature = "json")]
+    Json { timings: bool },
+    /// `JUnit` XML for CI systems, see [`Report::to_junit`].
+    JUnit { policy: ExitPolicy },
+    /// Markdown tables for a README, see [`Markdown`].
+    Markdown(Markdown),
+}
 
-        println!(
-            "{} {}",
-            request,
-            display::render_day_return(time_key, &day_return)
-        );
+/// The `check_*` and `run_*` methods of a [`Handler`], returning a [`Report`] instead of printing it.
+/// Obtained through [`Handler::reports`].
+pub struct Reports<'h> {
+    handler: &'h Handler<'h>,
+}
+
+impl Reports<'_> {
+    /// Same as [`Handler::run_year_range`], returning the report instead of printing it.
+    pub fn run_year_range(
+        &self,
+        years: impl Iterator<Item = Year>,
+    ) -> Result<Report, HandlerError> {
+        catching(|| self.year_range(Request::CheckAndRun, years))
     }
 
-    fn process_most_recent_part(&self, request: Request, year: Year) {
-        let time_key = TimeKey::<TimeDetailNone>::new(year).unwrap_or_else(|tke| {
-            eprintln!("{tke}");
-            std::process::exit(0)
-        });
+    /// Same as [`Handler::run_year`], returning the report instead of printing it.
+    pub fn run_year(&self, year: Year) -> Result<Report, HandlerError> {
+        catching(|| self.year(Request::CheckAndRun, year))
+    }
+
+    /// Same as [`Handler::run_most_recent_day`], returning the report instead of printing it.
+    pub fn run_most_recent_day(&self, year: Year) -> Result<Report, HandlerError> {
+        catching(|| self.most_recent_day(Request::CheckAndRun, year))
+    }
 
-        let (day, part, part_return) = self.execute_most_recent_part(request, time_key);
+    /// Same as [`Handler::run_day`], returning the report instead of printing it.
+    pub fn run_day(&self, year: Year, day: Day) -> Result<Report, HandlerError> {
+        catching(|| self.day(Request::CheckAndRun, year, day))
+    }
 
-        let time_key = TimeKey::<TimeDetailDayAndPart>::new(year, day, part)
-            .expect("Internal error: Most recent part was invalid.");
+    /// Same as [`Handler::run_most_recent_part`], returning the report instead of printing it.
+    pub fn run_most_recent_part(&self, year: Year) -> Result<Report, HandlerError> {
+        catching(|| self.most_recent_part(Request::CheckAndRun, year))
+    }
+
+    /// Same as [`Handler::run_part`], returning the report instead of printing it.
+    pub fn run_part(&self, year: Year, day: Day, part: Part) -> Result<Report, HandlerError> {
+        catching(|| self.part(Request::CheckAndRun, year, day, part))
+    }
 
-        println!(
-            "{} {}",
+    /// Same as [`Handler::run_part_on_input`], returning the report instead of printing it.
+    pub fn run_part_on_input(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        input: &str,
+        expected: impl Into<SolutionPart>,
+    ) -> Result<Report, HandlerError> {
+        catching(|| {
+            self.part_on(
+                CustomInput::Text(input.to_owned()),
+                year,
+                day,
+                part,
+                &expected.into(),
+            )
+        })
+    }
+
+    /// Same as [`Handler::run_part_on_file`], returning the report instead of printing it.
+    pub fn run_part_on_file(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        path: impl AsRef<std::path::Path>,
+        expected: impl Into<SolutionPart>,
+    ) -> Result<Report, HandlerError> {
+        catching(|| {
+            self.part_on(
+                CustomInput::File(path.as_ref().to_owned()),
+                year,
+                day,
+                part,
+                &expected.into(),
+            )
+        })
+    }
+
+    /// Same as [`Handler::run_part_on_stdin`], returning the report instead of printing it.
+    pub fn run_part_on_stdin(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        expected: impl Into<SolutionPart>,
+    ) -> Result<Report, HandlerError> {
+        catching(|| self.part_on(CustomInput::Stdin, year, day, part, &expected.into()))
+    }
+
+    /// Same as [`Handler::fuzz_part`], returning the report instead of printing it.
+    pub fn fuzz_part(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        generator: impl Fn(u64, usize) -> String,
+        runs: usize,
+        max_size: usize,
+    ) -> Result<Report, HandlerError> {
+        let time_key = TimeKey::<TimeDetailDayAndPart>::new(year, day, part)?;
+
+        catching(|| {
+            let part_return = self
+                .handler
+                .execute_fuzz(time_key, &generator, runs, max_size);
+
+            Ok(Report::new(
+                Request::Check,
+                Results::Part(year, day, part, part_return),
+            ))
+        })
+    }
+
+    /// Same as [`Handler::check_year_range`], returning the report instead of printing it.
+    pub fn check_year_range(
+        &self,
+        years: impl Iterator<Item = Year>,
+    ) -> Result<Report, HandlerError> {
+        catching(|| self.year_range(Request::Check, years))
+    }
+
+    /// Same as [`Handler::check_year`], returning the report instead of printing it.
+    pub fn check_year(&self, year: Year) -> Result<Report, HandlerError> {
+        catching(|| self.year(Request::Check, year))
+    }
+
+    /// Same as [`Handler::check_most_recent_day`], returning the report instead of printing it.
+    pub fn check_most_recent_day(&self, year: Year) -> Result<Report, HandlerError> {
+        catching(|| self.most_recent_day(Request::Check, year))
+    }
+
+    /// Same as [`Handler::check_day`], returning the report instead of printing it.
+    pub fn check_day(&self, year: Year, day: Day) -> Result<Report, HandlerError> {
+        catching(|| self.day(Request::Check, year, day))
+    }
+
+    /// Same as [`Handler::check_most_recent_part`], returning the report instead of printing it.
+    pub fn check_most_recent_part(&self, year: Year) -> Result<Report, HandlerError> {
+        catching(|| self.most_recent_part(Request::Check, year))
+    }
+
+    /// Same as [`Handler::check_part`], returning the report instead of printing it.
+    pub fn check_part(&self, year: Year, day: Day, part: Part) -> Result<Report, HandlerError> {
+        catching(|| self.part(Request::Check, year, day, part))
+    }
+
+    fn year_range(
+        &self,
+        request: Request,
+        years: impl Iterator<Item = Year>,
+    ) -> Result<Report, HandlerError> {
+        let year_returns = self
+            .handler
+            .execute_year_range(request, valid_years(years)?.into_iter())
+            .map(|(time_key, day_returns)| (time_key.year, day_returns.collect()))
+            .collect();
+
+        Ok(Report::new(request, Results::Years(year_returns)))
+    }
+
+    fn year(&self, request: Request, year: Year) -> Result<Report, HandlerError> {
+        let time_key = TimeKey::<TimeDetailNone>::new(year)?;
+
+        let day_returns = self.handler.execute_year(request, time_key).collect();
+
+        Ok(Report::new(request, Results::Year(year, day_returns)))
+    }
+
+    fn most_recent_day(&self, request: Request, year: Year) -> Result<Report, HandlerError> {
+        let time_key = TimeKey::<TimeDetailNone>::new(year)?;
+
+        let (day, day_return) = self.handler.execute_most_recent_day(request, time_key);
+
+        Ok(Report::new(request, Results::Day(year, day, day_return)))
+    }
+
+    fn day(&self, request: Request, year: Year, day: Day) -> Result<Report, HandlerError> {
+        let time_key = TimeKey::<TimeDetailDay>::new(year, day)?;
+
+        let day_return = self.handler.execute_day(request, time_key);
+
+        Ok(Report::new(request, Results::Day(year, day, day_return)))
+    }
+
+    fn most_recent_part(&self, request: Request, year: Year) -> Result<Report, HandlerError> {
+        let time_key = TimeKey::<TimeDetailNone>::new(year)?;
+
+        let (day, part, part_return) = self.handler.execute_most_recent_part(request, time_key);
+
+        Ok(Report::new(
             request,
-            display::render_part_return(time_key, &part_return)
-        );
+            Results::Part(year, day, part, part_return),
+        ))
     }
 
-    fn process_part(&self, request: Request, year: Year, day: Day, part: Part) {
-        let time_key =
-            TimeKey::<TimeDetailDayAndPart>::new(year, day, part).unwrap_or_else(|tke| {
-                eprintln!("Unable to check part: {tke}");
-                std::process::exit(0)
-            });
+    fn part(
+        &self,
+        request: Request,
+        year: Year,
+        day: Day,
+        part: Part,
+    ) -> Result<Report, HandlerError> {
+        let time_key = TimeKey::<TimeDetailDayAndPart>::new(year, day, part)?;
 
-        let part_return = self.execute_part(request, time_key, &std::cell::OnceCell::new());
+        let part_return =
+            self.handler
+                .execute_part(request, time_key, &crate::execution::DayInputs::default());
 
-        println!(
-            "{} {}",
+        Ok(Report::new(
             request,
-            display::render_part_return(time_key, &part_return)
+            Results::Part(year, day, part, part_return),
+        ))
+    }
+
+    fn part_on(
+        &self,
+        input: CustomInput,
+        year: Year,
+        day: Day,
+        part: Part,
+        expected: &SolutionPart,
+    ) -> Result<Report, HandlerError> {
+        let time_key = TimeKey::<TimeDetailDayAndPart>::new(year, day, part)?;
+
+        let input = input.read()?;
+
+        let part_return = self.handler.execute_part_on(time_key, input, expected);
+
+        Ok(Report::new(
+            Request::CheckAndRun,
+            Results::Part(year, day, part, part_return),
+        ))
+    }
+}
+
+/// Deduplicates and orders the years, reporting and skipping any invalid ones.
+/// The range only fails if none of its years are valid.
+fn valid_years(
+    years: impl Iterator<Item = Year>,
+) -> Result<std::collections::BTreeSet<TimeKey<TimeDetailNone>>, HandlerError> {
+    let (years, errors): (std::collections::BTreeSet<_>, std::collections::HashSet<_>) =
+        itertools::Itertools::partition_result(years.map(TimeKey::<TimeDetailNone>::new));
+
+    if years.is_empty() {
+        if let Some(tke) = errors.into_iter().next() {
+            return Err(tke.into());
+        }
+    } else if !errors.is_empty() {
+        eprintln!("Skipping invalid years:");
+        for tke in errors {
+            eprintln!("{tke}");
+        }
+    }
+    Ok(years)
+}
+
+/// Solvers are user code, so a panicking one is turned into an error rather than taking the caller down with it.
+fn catching(f: impl FnOnce() -> Result<Report, HandlerError>) -> Result<Report, HandlerError> {
+    //? Nothing the handler keeps across calls is left half-updated by a panicking solver
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_owned())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(HandlerError::Panicked(message))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Handler, HandlerError, Results, time_key::TimeKeyError};
+
+    #[test]
+    fn invalid_years_are_skipped_unless_all_are_invalid() {
+        let mapper = |_year, _day| None;
+        let handler = Handler::new(&mapper).with_offline(true);
+
+        let report = handler
+            .reports()
+            .check_year_range([2014, 2015, 2015].into_iter())
+            .unwrap();
+        let Results::Years(years) = report.results() else {
+            panic!("A range should report years");
+        };
+        assert_eq!(
+            years.iter().map(|(year, _)| *year).collect::<Vec<_>>(),
+            [2015]
         );
+
+        assert!(matches!(
+            handler.reports().check_year_range([2013, 2014].into_iter()),
+            Err(HandlerError::TimeKey(TimeKeyError::YearTooLow(_)))
+        ));
     }
 }
@@ -0,0 +1,119 @@
+use crate::{
+    Day, Part, Year,
+    api::display,
+    execution::{
+        Request,
+        result::{DayReturn, PartReturn},
+    },
+    time_key::{TimeDetailDay, TimeDetailDayAndPart, TimeDetailNone, TimeKey},
+};
+
+/// The results of a `check_*` or `run_*` call, for inspecting them programmatically or rendering them later.
+///
+/// ```no_run
+/// use aoc_handler::{Day, Handler, HandlerError, Solver, Year};
+///
+/// fn main() -> Result<(), HandlerError> {
+///     let mapper = |_year: Year, _day: Day| -> Option<Solver> { None };
+///     let handler = Handler::new(&mapper);
+///
+///     let report = handler.reports().check_year(2024)?;
+///     let failures = report.parts().filter(|(.., part_return)| {
+///         part_return.as_ref().is_ok_and(|output| output.outcome.failed())
+///     });
+///     println!("{} failing parts", failures.count());
+///     println!("{}", report.render());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Report {
+    request: Request,
+    results: Results,
+}
+
+/// The results a report holds, at the granularity of the call that produced it.
+#[derive(Debug, Clone)]
+pub enum Results {
+    Years(Vec<(Year, Vec<DayReturn>)>),
+    Year(Year, Vec<DayReturn>), //Days are in order, starting at day 1
+    Day(Year, Day, DayReturn),
+    Part(Year, Day, Part, PartReturn),
+}
+
+impl Report {
+    pub(crate) fn new(request: Request, results: Results) -> Self {
+        Self { request, results }
+    }
+
+    pub fn request(&self) -> Request {
+        self.request
+    }
+
+    pub fn results(&self) -> &Results {
+        &self.results
+    }
+
+    /// Every part the report covers, regardless of its granularity.
+    pub fn parts(&self) -> impl Iterator<Item = (Year, Day, Part, &PartReturn)> {
+        let parts: Vec<_> = match &self.results {
+            Results::Years(years) => years
+                .iter()
+                .flat_map(|(year, days)| {
+                    (1..).zip(days).flat_map(move |(day, day_return)| {
+                        (1..)
+                            .zip(day_return)
+                            .map(move |(part, part_return)| (*year, day, part, part_return))
+                    })
+                })
+                .collect(),
+            Results::Year(year, days) => (1..)
+                .zip(days)
+                .flat_map(|(day, day_return)| {
+                    (1..)
+                        .zip(day_return)
+                        .map(move |(part, part_return)| (*year, day, part, part_return))
+                })
+                .collect(),
+            Results::Day(year, day, day_return) => (1..)
+                .zip(day_return)
+                .map(|(part, part_return)| (*year, *day, part, part_return))
+                .collect(),
+            Results::Part(year, day, part, part_return) => {
+                vec![(*year, *day, *part, part_return)]
+            }
+        };
+        parts.into_iter()
+    }
+
+    /// Renders the report the same way the printing `check_*` and `run_*` methods do.
+    pub fn render(&self) -> String {
+        let rendered = match &self.results {
+            Results::Years(years) => {
+                display::render_multiple_year_results(years.iter().map(|(year, days)| {
+                    (
+                        TimeKey::<TimeDetailNone>::new(*year)
+                            .expect("Internal error: Reported year was invalid."),
+                        days.iter().cloned(),
+                    )
+                }))
+            }
+            Results::Year(year, days) => display::render_year_returns(
+                TimeKey::<TimeDetailNone>::new(*year)
+                    .expect("Internal error: Reported year was invalid."),
+                days.iter().cloned(),
+            ),
+            Results::Day(year, day, day_return) => display::render_day_return(
+                TimeKey::<TimeDetailDay>::new(*year, *day)
+                    .expect("Internal error: Reported day was invalid."),
+                day_return,
+            ),
+            Results::Part(year, day, part, part_return) => display::render_part_return(
+                TimeKey::<TimeDetailDayAndPart>::new(*year, *day, *part)
+                    .expect("Internal error: Reported part was invalid."),
+                part_return,
+            ),
+        };
+        format!("{} {rendered}", self.request)
+    }
+}
@@ -1,4 +1,40 @@
-use crate::{SolutionPart, Solver, SolverPart, TestInput};
+use crate::{Implementation, SolutionPart, Solver, TestCase, TestInput};
+
+//? Tests and alternatives are the same however a day is solved, so every solver trait declares and attaches them through these
+macro_rules! part_hooks {
+    () => {
+        fn part_1_test_input() -> impl Into<TestInput> {}
+        fn part_1_test_answer() -> impl Into<SolutionPart> {}
+        fn part_2_test_input() -> impl Into<TestInput> {
+            Self::part_1_test_input()
+        }
+        fn part_2_test_answer() -> impl Into<SolutionPart> {}
+        fn part_1_test_cases() -> Vec<TestCase> {
+            Vec::new()
+        }
+        fn part_2_test_cases() -> Vec<TestCase> {
+            Vec::new()
+        }
+        fn part_1_alternatives() -> Vec<Implementation> {
+            Vec::new()
+        }
+        fn part_2_alternatives() -> Vec<Implementation> {
+            Vec::new()
+        }
+    };
+}
+
+macro_rules! with_part_hooks {
+    ($solver:expr) => {
+        $solver
+            .with_part_1_test(Self::part_1_test_input(), Self::part_1_test_answer())
+            .with_part_2_test(Self::part_2_test_input(), Self::part_2_test_answer())
+            .with_part_1_test_cases(Self::part_1_test_cases())
+            .with_part_2_test_cases(Self::part_2_test_cases())
+            .with_part_1_alternatives(Self::part_1_alternatives())
+            .with_part_2_alternatives(Self::part_2_alternatives())
+    };
+}
 
 /// A template for a zero-size type providing solution functions for a given day.
 /// Test functions can be overridden.
@@ -8,6 +44,7 @@ use crate::{SolutionPart, Solver, SolverPart, TestInput};
 ///
 /// struct DayImpl;
 ///
+/// # fn parse(input: &str) -> Vec<&str> { input.lines().collect() }
 /// impl DaySolver for DayImpl {
 ///     // Example: "Count the lines in the input."
 ///     fn part_1(input: &str) -> impl Into<SolutionPart> {
@@ -25,7 +62,7 @@ use crate::{SolutionPart, Solver, SolverPart, TestInput};
 ///         Line 2  
 ///         Line 3
 ///         Line 4"
-///         // Leading whitespace on lines in the input is automatically removed
+///         // Indentation common to the lines is removed, relative indentation is kept
 ///     }
 ///
 ///     fn part_1_test_answer() -> impl Into<SolutionPart> {
@@ -42,28 +79,115 @@ use crate::{SolutionPart, Solver, SolverPart, TestInput};
 pub trait DaySolver {
     fn part_1(input: &str) -> impl Into<SolutionPart>;
     fn part_2(input: &str) -> impl Into<SolutionPart>;
-    fn part_1_test_input() -> impl Into<TestInput> {}
-    fn part_1_test_answer() -> impl Into<SolutionPart> {}
-    fn part_2_test_input() -> impl Into<TestInput> {
-        Self::part_1_test_input()
-    }
-    fn part_2_test_answer() -> impl Into<SolutionPart> {}
+    part_hooks!();
 }
 
 pub trait WrapSolver: Sized + DaySolver {
     fn wrap() -> Option<Solver> {
-        Some(Solver {
-            part_1: SolverPart {
-                solver: &move |input| Self::part_1(input).into(),
-                test_input: Self::part_1_test_input().into(),
-                test_answer: Self::part_1_test_answer().into(),
-            },
-            part_2: SolverPart {
-                solver: &move |input| Self::part_2(input).into(),
-                test_input: Self::part_2_test_input().into(),
-                test_answer: Self::part_2_test_answer().into(),
-            },
-        })
+        Some(with_part_hooks!(Solver::new(
+            |input| Self::part_1(input).into(),
+            |input| Self::part_2(input).into(),
+        )))
     }
 }
 impl<DS: DaySolver> WrapSolver for DS {}
+
+/// A variant of [`DaySolver`] for days where both parts work on the same parsed representation.
+/// The input is parsed once and shared between the parts, and the parse step is timed separately.
+///
+/// ```
+/// use aoc_handler::{ParsedDaySolver, SolutionPart, TestInput};
+///
+/// struct DayImpl;
+///
+/// impl ParsedDaySolver for DayImpl {
+///     type Parsed = Vec<u32>;
+///
+///     fn parse(input: &str) -> Self::Parsed {
+///         input.lines().map(|line| line.parse().unwrap()).collect()
+///     }
+///
+///     fn part_1(input: &Self::Parsed) -> impl Into<SolutionPart> {
+///         input.iter().sum::<u32>()
+///     }
+///
+///     fn part_2(input: &Self::Parsed) -> impl Into<SolutionPart> {
+///         *input.iter().max().unwrap()
+///     }
+///
+///     fn part_1_test_input() -> impl Into<TestInput> {
+///         "1
+///         2
+///         3"
+///     }
+///
+///     fn part_1_test_answer() -> impl Into<SolutionPart> {
+///         6
+///     }
+/// }
+/// ```
+pub trait ParsedDaySolver {
+    type Parsed: 'static;
+    fn parse(input: &str) -> Self::Parsed;
+    fn part_1(input: &Self::Parsed) -> impl Into<SolutionPart>;
+    fn part_2(input: &Self::Parsed) -> impl Into<SolutionPart>;
+    part_hooks!();
+}
+
+pub trait WrapParsedSolver: Sized + ParsedDaySolver {
+    fn wrap() -> Option<Solver> {
+        Some(with_part_hooks!(Solver::parsed(
+            |input| Self::parse(input),
+            |input| Self::part_1(input).into(),
+            |input| Self::part_2(input).into(),
+        )))
+    }
+}
+impl<PDS: ParsedDaySolver> WrapParsedSolver for PDS {}
+
+/// A variant of [`DaySolver`] for days where both parts fall out of the same computation.
+///
+/// Useful when part two is a by-product of part one, for example the same search with a different readout.
+/// Both answers are produced in one pass, each is checked against its own test answer, and the timing covers both.
+///
+/// ```
+/// use aoc_handler::{CombinedDaySolver, SolutionPart, TestInput};
+///
+/// struct DayImpl;
+///
+/// impl CombinedDaySolver for DayImpl {
+///     // Example: "Sum the numbers, and count the even ones."
+///     fn solve(input: &str) -> (impl Into<SolutionPart>, impl Into<SolutionPart>) {
+///         let numbers = input.lines().map(|line| line.parse::<u32>().unwrap());
+///         numbers.fold((0, 0), |(sum, even), n| (sum + n, even + u32::from(n % 2 == 0)))
+///     }
+///
+///     fn part_1_test_input() -> impl Into<TestInput> {
+///         "1
+///         2
+///         3"
+///     }
+///
+///     fn part_1_test_answer() -> impl Into<SolutionPart> {
+///         6
+///     }
+///
+///     fn part_2_test_answer() -> impl Into<SolutionPart> {
+///         1
+///     }
+/// }
+/// ```
+pub trait CombinedDaySolver {
+    fn solve(input: &str) -> (impl Into<SolutionPart>, impl Into<SolutionPart>);
+    part_hooks!();
+}
+
+pub trait WrapCombinedSolver: Sized + CombinedDaySolver {
+    fn wrap() -> Option<Solver> {
+        Some(with_part_hooks!(Solver::combined(|input| {
+            let (part_1, part_2) = Self::solve(input);
+            (part_1.into(), part_2.into())
+        })))
+    }
+}
+impl<CDS: CombinedDaySolver> WrapCombinedSolver for CDS {}
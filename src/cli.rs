@@ -0,0 +1,370 @@
+use crate::{
+    Day, ExitPolicy, Failure, Handler, HandlerError, Markdown, OutputFormat, Part, Report, Results,
+    Scaffold, Theme, Year,
+    execution::Request,
+    time_key::{self, TimeDetailNone, TimeKey},
+};
+
+/// Check and run Advent of Code solutions.
+#[derive(Debug, clap::Parser)]
+#[command(
+    after_help = "Examples:\n  run 2024\n  check 2024 5\n  run 2024 5 2\n  run 2015..=2020\n  latest\n  new 2024 6"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Only use cached inputs, never fetch from the Advent of Code servers
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// When to colour the output
+    #[arg(long, global = true, value_enum, default_value_t = Color::Auto)]
+    color: Color,
+
+    /// Draw with ASCII characters only
+    #[arg(long, global = true)]
+    ascii: bool,
+
+    /// How to print the results
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Also fail on unimplemented and unchecked parts
+    #[arg(long, global = true)]
+    strict: bool,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum Command {
+    /// Check against the test inputs, then run on the real input
+    Run(Target),
+    /// Only check against the test inputs
+    Check(Target),
+    /// Run the most recently implemented part
+    Latest {
+        /// Defaults to the most recent year with any solutions
+        year: Option<Year>,
+        /// Run the whole most recently implemented day instead
+        #[arg(long)]
+        day: bool,
+        /// Only check against the test inputs
+        #[arg(long)]
+        check: bool,
+    },
+    /// Set up a new day: Its module, its mapper entry, its input and its example
+    New { year: Year, day: Day },
+    /// Pick what to check, run or benchmark from an interactive menu
+    #[cfg(feature = "interactive")]
+    Menu,
+}
+
+#[derive(Debug, clap::Args)]
+struct Target {
+    /// A single year, or a range such as 2015..2020 or 2015..=2020
+    #[arg(value_parser = parse_years)]
+    years: Years,
+    day: Option<Day>,
+    part: Option<Part>,
+}
+
+/// Why a target given on the command line produced no report.
+#[derive(Debug, thiserror::Error)]
+enum TargetError {
+    #[error(transparent)]
+    Usage(#[from] clap::Error),
+    #[error(transparent)]
+    Handler(#[from] HandlerError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Years {
+    Single(Year),
+    Range(std::ops::RangeInclusive<Year>),
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum Color {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum Format {
+    Text,
+    /// The full result tree, see `Report::to_json`
+    #[cfg(feature = "json")]
+    Json,
+    /// The same, leaving out the timings so that runs can be diffed
+    #[cfg(feature = "json")]
+    JsonWithoutTimings,
+    /// `JUnit` XML, for CI systems
+    Junit,
+    /// A Markdown table of stars, answers and run times, for a README
+    Markdown,
+}
+
+impl Handler<'_> {
+    /// A command line front end, for a `main` that doesn't need to be edited to switch between days and modes.
+    /// Requires the `cli` feature. Run with `--help` for the available commands.
+    ///
+    /// ```no_run
+    /// use aoc_handler::{Day, Handler, Solver, Year};
+    ///
+    /// fn main() -> std::process::ExitCode {
+    ///     let mapper = |_year: Year, _day: Day| -> Option<Solver> { None };
+    ///     Handler::new(&mapper).run_cli()
+    /// }
+    /// ```
+    pub fn run_cli(self) -> std::process::ExitCode {
+        self.run_cli_from(std::env::args_os())
+    }
+
+    /// Same as [`Handler::run_cli`], with the arguments provided directly. The first one is the program name.
+    pub fn run_cli_from(
+        mut self,
+        args: impl IntoIterator<Item = impl Into<std::ffi::OsString> + Clone>,
+    ) -> std::process::ExitCode {
+        let cli = <Cli as clap::Parser>::parse_from(args);
+        if cli.offline {
+            self = self.with_offline(true);
+        }
+        let policy = if cli.strict {
+            ExitPolicy::strict()
+        } else {
+            ExitPolicy::lenient()
+        };
+        match cli.format {
+            Format::Text => (),
+            #[cfg(feature = "json")]
+            Format::Json => self = self.with_output_format(OutputFormat::Json { timings: true }),
+            #[cfg(feature = "json")]
+            Format::JsonWithoutTimings => {
+                self = self.with_output_format(OutputFormat::Json { timings: false });
+            }
+            Format::Junit => self = self.with_output_format(OutputFormat::JUnit { policy }),
+            Format::Markdown => {
+                self = self.with_output_format(OutputFormat::Markdown(Markdown::default()));
+            }
+        }
+
+        let theme = match cli.color {
+            Color::Always => Theme::current().with_styles(true),
+            Color::Never => Theme::plain(),
+            Color::Auto => Theme::detect(),
+        };
+        Theme::set(theme.with_ascii(cli.ascii));
+
+        let report = match cli.command {
+            #[cfg(feature = "interactive")]
+            Command::Menu => {
+                return match self.interactive() {
+                    Ok(()) => std::process::ExitCode::SUCCESS,
+                    Err(e) => {
+                        eprintln!("{e}");
+                        std::process::ExitCode::FAILURE
+                    }
+                };
+            }
+            Command::New { year, day } => {
+                return match self.scaffold_day_with(year, day, &Scaffold::default()) {
+                    Ok(steps) => {
+                        for step in steps {
+                            println!("{step}");
+                        }
+                        std::process::ExitCode::SUCCESS
+                    }
+                    Err(se) => {
+                        eprintln!("{se}");
+                        std::process::ExitCode::FAILURE
+                    }
+                };
+            }
+            Command::Run(target) => self.cli_target(Request::CheckAndRun, target),
+            Command::Check(target) => self.cli_target(Request::Check, target),
+            Command::Latest { year, day, check } => {
+                let reports = self.reports();
+                let year = year.unwrap_or_else(|| self.latest_year());
+                match (day, check) {
+                    (false, false) => reports.run_most_recent_part(year),
+                    (false, true) => reports.check_most_recent_part(year),
+                    (true, false) => reports.run_most_recent_day(year),
+                    (true, true) => reports.check_most_recent_day(year),
+                }
+                .map(|report| (report, false))
+                .map_err(TargetError::Handler)
+            }
+        };
+
+        match report {
+            Ok((report, shown)) => {
+                let exit_code = report.exit_code(policy);
+                if !(shown && self.format == OutputFormat::Text) {
+                    self.print_report(Ok(report));
+                }
+                exit_code
+            }
+            Err(TargetError::Usage(ce)) => {
+                eprintln!("{ce}");
+                Failure::InputError.into()
+            }
+            Err(TargetError::Handler(he)) => {
+                let failure = match he {
+                    HandlerError::TimeKey(_) | HandlerError::Input(_) => Failure::InputError,
+                    HandlerError::Panicked(_) => Failure::Panicked,
+                };
+                self.print_report(Err(he));
+                failure.into()
+            }
+        }
+    }
+
+    /// Also returns whether the report was already shown while it ran.
+    fn cli_target(&self, request: Request, target: Target) -> Result<(Report, bool), TargetError> {
+        let reports = self.reports();
+        let run = matches!(request, Request::CheckAndRun);
+        let report = match (target.years, target.day, target.part) {
+            //? Years on a terminal are shown as they complete
+            (years, None, None) if self.streams() => {
+                return self
+                    .cli_stream(request, years)
+                    .map(|report| (report, true))
+                    .map_err(TargetError::Handler);
+            }
+            (Years::Single(year), None, None) if run => reports.run_year(year),
+            (Years::Single(year), None, None) => reports.check_year(year),
+            (Years::Range(years), None, None) if run => reports.run_year_range(years),
+            (Years::Range(years), None, None) => reports.check_year_range(years),
+            (Years::Single(year), Some(day), None) if run => reports.run_day(year, day),
+            (Years::Single(year), Some(day), None) => reports.check_day(year, day),
+            (Years::Single(year), Some(day), Some(part)) if run => {
+                reports.run_part(year, day, part)
+            }
+            (Years::Single(year), Some(day), Some(part)) => reports.check_part(year, day, part),
+            (Years::Range(_), Some(_), _) => {
+                return Err(TargetError::Usage(
+                    <Cli as clap::CommandFactory>::command().error(
+                        clap::error::ErrorKind::ArgumentConflict,
+                        "A day can only be given for a single year, not a range of years",
+                    ),
+                ));
+            }
+            (_, None, Some(_)) => unreachable!("Clap only accepts a part after a day"),
+        }?;
+        Ok((report, false))
+    }
+
+    fn cli_stream(&self, request: Request, years: Years) -> Result<Report, HandlerError> {
+        print!("{request} ");
+        match years {
+            Years::Single(year) => {
+                let time_key = TimeKey::<TimeDetailNone>::new(year)?;
+                let day_returns = self.stream_year(request, time_key);
+                Ok(Report::new(request, Results::Year(year, day_returns)))
+            }
+            Years::Range(years) => {
+                //? The years were already validated while parsing the arguments
+                let year_returns = years
+                    .filter_map(|year| TimeKey::<TimeDetailNone>::new(year).ok())
+                    .map(|time_key| (time_key.year, self.stream_year(request, time_key)))
+                    .collect();
+                Ok(Report::new(request, Results::Years(year_returns)))
+            }
+        }
+    }
+
+    /// The most recent year any day is mapped for.
+    fn latest_year(&self) -> Year {
+        let current_year = time_key::current_year();
+
+        (2015..=current_year)
+            .rev()
+            .find(|&year| {
+                TimeKey::<TimeDetailNone>::new(year).is_ok_and(|key| {
+                    key.iterate().any(|key| {
+                        let (year, day) = key.to_primitive();
+                        (self.mapper)(year, day).is_some()
+                    })
+                })
+            })
+            .unwrap_or(current_year)
+    }
+}
+
+/// Validates the dates up front, so that mistakes are reported alongside the usage rather than as a failed run.
+/// Ranges have to contain at least one year, and all of their years have to be valid.
+fn parse_years(arg: &str) -> Result<Years, String> {
+    let number = |year: &str| -> Result<Year, String> {
+        year.trim()
+            .parse()
+            .map_err(|_| format!("'{year}' is not a year"))
+    };
+    let valid = |year: Year| -> Result<Year, String> {
+        TimeKey::<TimeDetailNone>::new(year).map_err(|tke| tke.to_string())?;
+        Ok(year)
+    };
+    let range = |start: Year, end: Option<Year>| -> Result<Years, String> {
+        match end {
+            Some(end) if start <= end => Ok(Years::Range(valid(start)?..=valid(end)?)),
+            _ => Err(format!("'{arg}' contains no years")),
+        }
+    };
+
+    if let Some((start, end)) = arg.split_once("..=") {
+        range(number(start)?, Some(number(end)?))
+    } else if let Some((start, end)) = arg.split_once("..") {
+        //? The end is exclusive, so it's the year before it that has to be valid
+        range(number(start)?, number(end)?.checked_sub(1))
+    } else {
+        number(arg).and_then(valid).map(Years::Single)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Years, parse_years};
+    use crate::{Day, Failure, Handler, Solver, Year};
+
+    #[test]
+    fn parses_single_years() {
+        assert_eq!(parse_years("2016"), Ok(Years::Single(2016)));
+        assert_eq!(parse_years(" 2016 "), Ok(Years::Single(2016)));
+        assert!(parse_years("2014").is_err());
+        assert!(parse_years("twenty").is_err());
+    }
+
+    #[test]
+    fn parses_inclusive_ranges() {
+        assert_eq!(parse_years("2015..=2017"), Ok(Years::Range(2015..=2017)));
+        assert_eq!(parse_years("2016..=2016"), Ok(Years::Range(2016..=2016)));
+        assert!(parse_years("2014..=2016").is_err());
+    }
+
+    #[test]
+    fn parses_exclusive_ranges_up_to_the_year_before_the_end() {
+        assert_eq!(parse_years("2015..2017"), Ok(Years::Range(2015..=2016)));
+        assert_eq!(parse_years("2015..2016"), Ok(Years::Range(2015..=2015)));
+    }
+
+    #[test]
+    fn validates_the_last_year_of_exclusive_ranges_rather_than_the_end() {
+        //? 2015 is the first year, so 2015..2015 is empty and 2014..2016 still starts too early
+        assert!(parse_years("2015..2015").is_err());
+        assert!(parse_years("2014..2016").is_err());
+        assert!(parse_years("2015..0").is_err());
+    }
+
+    #[test]
+    fn rejects_a_day_for_a_range_of_years_without_exiting() {
+        let mapper = |_year: Year, _day: Day| -> Option<Solver> { None };
+        let exit_code = Handler::new(&mapper).run_cli_from(["aoc", "check", "2015..2017", "3"]);
+        assert_eq!(exit_code, Failure::InputError.into());
+    }
+
+    #[test]
+    fn rejects_empty_ranges() {
+        assert!(parse_years("2017..2016").is_err());
+        assert!(parse_years("2017..=2016").is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use std::cell::RefCell;
+
+/// Information about the input a solver is currently running on.
+///
+/// Many puzzles use different constants for the example than for the real input, such as a smaller grid or fewer steps.
+/// Parameters can be declared alongside a test input, and read back from within the solver.
+///
+/// ```
+/// use aoc_handler::{Context, DaySolver, SolutionPart, TestInput};
+///
+/// struct DayImpl;
+///
+/// impl DaySolver for DayImpl {
+///     fn part_1(input: &str) -> impl Into<SolutionPart> {
+///         let size: usize = Context::param("size").unwrap_or(71);
+///         input.lines().filter(|line| line.len() < size).count()
+///     }
+///
+///     fn part_2(_input: &str) -> impl Into<SolutionPart> {}
+///
+///     fn part_1_test_input() -> impl Into<TestInput> {
+///         TestInput::from("....\n........").with_param("size", 7)
+///     }
+///
+///     fn part_1_test_answer() -> impl Into<SolutionPart> {
+///         1
+///     }
+/// }
+/// ```
+pub struct Context;
+
+impl Context {
+    /// Whether the solver is currently running on a test input, as opposed to the real puzzle input.
+    pub fn is_test() -> bool {
+        CURRENT.with_borrow(|current| current.as_ref().is_some_and(|scope| scope.is_test))
+    }
+
+    /// A parameter declared with [`TestInput::with_param`](crate::TestInput::with_param), parsed into the requested type.
+    /// Always `None` when running on the real input, or when the parameter is missing or fails to parse.
+    pub fn param<T: std::str::FromStr>(name: &str) -> Option<T> {
+        CURRENT.with_borrow(|current| {
+            current
+                .as_ref()?
+                .params
+                .iter()
+                .find(|(key, _)| key == name)
+                .and_then(|(_, value)| value.parse().ok())
+        })
+    }
+
+    /// Logs a line of debug output, which is captured per part and shown alongside it instead of interleaving with the results.
+    /// Outside of the handler, the line is printed to stdout. [`aoc_println!`](crate::aoc_println) formats its arguments first.
+    pub fn log(line: impl std::fmt::Display) {
+        let uncaptured = LOG.with_borrow_mut(|log| match log {
+            Some(log) => {
+                use std::fmt::Write;
+                let _ = writeln!(log, "{line}");
+                None
+            }
+            None => Some(line),
+        });
+        if let Some(line) = uncaptured {
+            println!("{line}");
+        }
+    }
+}
+
+/// Logs a line through [`Context::log`], with the arguments of [`println!`].
+///
+/// ```
+/// use aoc_handler::aoc_println;
+///
+/// let steps = 12;
+/// aoc_println!("Took {steps} steps");
+/// ```
+#[macro_export]
+macro_rules! aoc_println {
+    () => {
+        $crate::Context::log("")
+    };
+    ($($arg:tt)*) => {
+        $crate::Context::log(::core::format_args!($($arg)*))
+    };
+}
+
+pub type Params = Vec<(String, String)>;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scope {
+    pub is_test: bool,
+    pub params: Params,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Scope>> = const { RefCell::new(None) };
+    static LOG: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Collects everything logged through [`Context::log`] while `f` runs, without it reaching the logs of any enclosing capture.
+pub fn capture_log<T>(f: impl FnOnce() -> T) -> (T, String) {
+    let guard = LogGuard {
+        previous: LOG.replace(Some(String::new())),
+    };
+    let ret = f();
+    let captured = LOG.take().unwrap_or_default();
+    drop(guard);
+    (ret, captured.trim_end().to_owned())
+}
+
+/// Restores the enclosing capture, even if the solver panics.
+/// In that case, everything captured up to then is printed to stderr, so it isn't lost along with the part.
+struct LogGuard {
+    previous: Option<String>,
+}
+
+impl Drop for LogGuard {
+    fn drop(&mut self) {
+        let captured = LOG.replace(self.previous.take());
+        if std::thread::panicking() {
+            eprint!("{}", captured.unwrap_or_default());
+        }
+    }
+}
+
+impl Scope {
+    /// Makes this scope visible through [`Context`] for the duration of `f`.
+    pub fn enter<T>(&self, f: impl FnOnce() -> T) -> T {
+        let _guard = ScopeGuard {
+            previous: CURRENT.replace(Some(self.clone())),
+        };
+        f()
+    }
+}
+
+/// Restores the enclosing scope, even if the solver panics, so that a test scope doesn't outlive its test.
+struct ScopeGuard {
+    previous: Option<Scope>,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        CURRENT.set(self.previous.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scope;
+    use crate::{
+        Context, Handler, Results, Solver, TestInput,
+        result::{CheckReturn, CheckedRunReturn, PartOutcome},
+    };
+
+    #[test]
+    fn params_only_reach_the_solver_on_test_input() {
+        let part_1 = |_: &str| {
+            Context::param::<usize>("size").unwrap_or(71) + usize::from(Context::is_test()) * 1000
+        };
+        let mapper = |_year, _day| {
+            Some(
+                Solver::new(part_1, |_: &str| ())
+                    .with_part_1_test(TestInput::from("test").with_param("size", 7), 1007),
+            )
+        };
+        let handler = Handler::new(&mapper).with_offline(true);
+
+        let checked = handler.reports().check_part(2015, 1, 1).unwrap();
+        let Results::Part(.., Ok(output)) = checked.results() else {
+            panic!("The part should have been checked");
+        };
+        assert!(matches!(
+            output.outcome,
+            PartOutcome::Checked(CheckReturn::Passed(..))
+        ));
+
+        let ran = handler
+            .reports()
+            .run_part_on_input(2015, 1, 1, "real", 71)
+            .unwrap();
+        let Results::Part(.., Ok(output)) = ran.results() else {
+            panic!("The part should have run");
+        };
+        assert!(matches!(
+            output.outcome,
+            PartOutcome::CheckedAndRan(CheckedRunReturn::Ok { .. })
+        ));
+    }
+
+    #[test]
+    fn scopes_are_left_even_if_the_solver_panics() {
+        let scope = Scope {
+            is_test: true,
+            params: vec![("size".to_owned(), "7".to_owned())],
+        };
+        let panicked = std::panic::catch_unwind(|| scope.enter(|| panic!("solver failed")));
+        assert!(panicked.is_err());
+        assert!(!Context::is_test());
+        assert_eq!(Context::param::<usize>("size"), None);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{input_handler::InputError, time_key::TimeKeyError};
+
+/// Everything that can keep the [`Handler`](crate::Handler) from producing a report.
+/// Problems with individual parts, such as failing tests, are part of the report instead.
+#[derive(Debug, thiserror::Error)]
+pub enum HandlerError {
+    #[error(transparent)]
+    TimeKey(#[from] TimeKeyError),
+    #[error(transparent)]
+    Input(#[from] InputError),
+    #[error("A solver panicked: {0}")]
+    Panicked(String),
+}
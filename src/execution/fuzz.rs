@@ -0,0 +1,255 @@
+use crate::{
+    context::{Scope, capture_log},
+    execution::{
+        PreparedInput, resolve,
+        result::{
+            AcquisitionError, CheckReturn, FuzzReturn, PartOutcome, PartOutput, PartReturn,
+            Unchecked,
+        },
+        run_part,
+    },
+    input_handler::InputError,
+    time_key::{PartInternal, TimeDetailDayAndPart, TimeKey},
+};
+
+thread_local! {
+    //? Set while shrinking, when panics are expected and their messages would only drown out the report
+    static QUIET: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+impl crate::Handler<'_> {
+    /// Runs all implementations of a part on generated inputs, and compares their answers.
+    /// The first input they disagree on is shrunk, saved and returned as a counterexample.
+    pub(crate) fn execute_fuzz(
+        &self,
+        time_key: TimeKey<TimeDetailDayAndPart>,
+        generator: &dyn Fn(u64, usize) -> String,
+        runs: usize,
+        max_size: usize,
+    ) -> PartReturn {
+        //? Solvers run far too often here for their output to be of any use
+        let (outcome, _) = capture_log(|| self.fuzz_outcome(time_key, generator, runs, max_size));
+        outcome.map(|outcome| PartOutput {
+            outcome,
+            captured: String::new(),
+        })
+    }
+
+    fn fuzz_outcome(
+        &self,
+        time_key: TimeKey<TimeDetailDayAndPart>,
+        generator: &dyn Fn(u64, usize) -> String,
+        runs: usize,
+        max_size: usize,
+    ) -> Result<PartOutcome, AcquisitionError> {
+        let solver = {
+            let (year, day, _) = time_key.to_primitive();
+            (self.mapper)(year, day)
+        }
+        .ok_or(AcquisitionError::NotMapped)?;
+
+        let part = time_key.detail.part;
+        let solver_part = match part {
+            PartInternal::One => &solver.part_1,
+            PartInternal::Two => &solver.part_2,
+        };
+
+        if solver_part.alternatives.is_empty() {
+            return Ok(PartOutcome::Checked(CheckReturn::Unchecked(
+                Unchecked::NothingToCompare,
+            )));
+        }
+
+        let answers = |input: &str| {
+            run_part(
+                solver_part,
+                part,
+                &PreparedInput::new(solver.parser.as_ref(), input.to_owned(), Scope::default()),
+            )
+        };
+
+        //? Sizes ramp up over the runs, so that the first failure is likely to be small already
+        let failure = (0..runs)
+            .map(|run| (run as u64, 1 + run * max_size / runs.max(1)))
+            .find_map(|(seed, size)| {
+                let input = generator(seed, size);
+                let disagreement = answers(&input).err()?;
+                Some((seed, size, input, disagreement))
+            });
+
+        let Some((seed, size, input, disagreement)) = failure else {
+            return Ok(PartOutcome::Checked(CheckReturn::Fuzzed(
+                FuzzReturn::Agreed { runs },
+            )));
+        };
+
+        let shrunk = shrink(generator, seed, size, input.clone(), &|input| {
+            quietly(|| answers(input).is_err())
+        });
+        //? Non-deterministic generators or solvers may not reproduce the disagreement, in which case the original input stands
+        let (input, disagreement) = match quietly_result(|| answers(&shrunk)) {
+            Some(Err(shrunk_disagreement)) => (shrunk, shrunk_disagreement),
+            _ => (input, disagreement),
+        };
+        let answers = disagreement
+            .into_iter()
+            .map(|(name, rr)| (name, rr.solution_part))
+            .collect();
+
+        Ok(PartOutcome::Checked(CheckReturn::Fuzzed(
+            FuzzReturn::Disagreed {
+                saved_to: self.save_counterexample(time_key, &input),
+                input,
+                answers,
+            },
+        )))
+    }
+
+    /// Saves an input on which a part's implementations disagreed, so that it can be inspected and rerun.
+    /// Returns where it was saved.
+    fn save_counterexample(
+        &self,
+        key: TimeKey<TimeDetailDayAndPart>,
+        input: &str,
+    ) -> Result<std::path::PathBuf, InputError> {
+        let (year, day, part) = key.to_primitive();
+        let path = self
+            .counterexample_dir
+            .clone()
+            .unwrap_or_else(|| resolve(std::path::Path::new("examples")))
+            .join(year.to_string())
+            .join(format!("day{day:02}_{part}.counterexample.txt"));
+        path.parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&path, input))
+            .map_err(|e| InputError::CounterexampleWrite(path.clone(), std::rc::Rc::new(e)))?;
+        Ok(path)
+    }
+}
+
+/// Shrinks a failing input towards a small reproducer.
+/// First by asking the generator for smaller sizes with the same seed, then by deleting lines, and finally characters.
+fn shrink(
+    generator: &dyn Fn(u64, usize) -> String,
+    seed: u64,
+    size: usize,
+    failing: String,
+    still_fails: &dyn Fn(&str) -> bool,
+) -> String {
+    let input = (1..size)
+        .map(|size| generator(seed, size))
+        .find(|input| still_fails(input))
+        .unwrap_or(failing);
+
+    let lines = input.lines().map(str::to_owned).collect();
+    let lines = shrink_units(lines, "\n", still_fails);
+
+    match lines.as_slice() {
+        [line] => shrink_units(line.chars().map(String::from).collect(), "", still_fails).concat(),
+        _ => lines.join("\n"),
+    }
+}
+
+/// Removes ever smaller chunks of units, keeping every removal after which the input still fails.
+fn shrink_units(
+    mut units: Vec<String>,
+    separator: &str,
+    still_fails: &dyn Fn(&str) -> bool,
+) -> Vec<String> {
+    let mut chunk = units.len() / 2;
+    while chunk > 0 {
+        let mut removed_any = false;
+        let mut start = 0;
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+            if !candidate.is_empty() && still_fails(&candidate.join(separator)) {
+                units = candidate;
+                removed_any = true;
+            } else {
+                start = end;
+            }
+        }
+        if !removed_any {
+            chunk /= 2;
+        }
+    }
+    units
+}
+
+/// Shrunk inputs are frequently malformed, so a panicking solver just means the candidate is rejected.
+fn quietly(f: impl FnOnce() -> bool) -> bool {
+    quietly_result(f).unwrap_or(false)
+}
+
+/// Runs `f` without printing its panic message, returning `None` if it panicked.
+/// The panic hook is only wrapped once, and only silenced on this thread for the duration.
+fn quietly_result<T>(f: impl FnOnce() -> T) -> Option<T> {
+    static HOOK: std::sync::Once = std::sync::Once::new();
+    HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                previous(info);
+            }
+        }));
+    });
+
+    let was_quiet = QUIET.replace(true);
+    let ret = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).ok();
+    QUIET.set(was_quiet);
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{quietly, shrink};
+
+    //? One number per line, with a 7 somewhere once the size is large enough
+    fn generator(seed: u64, size: usize) -> String {
+        (0..size)
+            .map(|i| ((i as u64 * 3 + seed) % 10).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn contains_seven(input: &str) -> bool {
+        input.lines().any(|line| line == "7")
+    }
+
+    #[test]
+    fn shrinks_to_the_failing_line() {
+        let failing = generator(1, 50);
+        assert!(contains_seven(&failing));
+        assert_eq!(shrink(&generator, 1, 50, failing, &contains_seven), "7");
+    }
+
+    #[test]
+    fn shrinks_single_lines_by_characters() {
+        let generator = |_seed: u64, size: usize| "ab7cd".repeat(size);
+        let still_fails = |input: &str| input.contains('7');
+        assert_eq!(shrink(&generator, 0, 3, generator(0, 3), &still_fails), "7");
+    }
+
+    #[test]
+    fn keeps_the_failing_input_if_the_generator_is_not_deterministic() {
+        let calls = std::cell::Cell::new(0);
+        let generator = |_seed: u64, _size: usize| {
+            calls.set(calls.get() + 1);
+            "1\n2".to_owned()
+        };
+        let failing = "1\n7\n2".to_owned();
+        assert_eq!(shrink(&generator, 0, 5, failing, &contains_seven), "7");
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    fn panics_reject_candidates() {
+        let still_fails = |input: &str| quietly(|| input.parse::<u32>().unwrap() == 7);
+        let failing = "7\nx".to_owned();
+        assert_eq!(
+            shrink(&|_, _| String::new(), 0, 1, failing, &still_fails),
+            "7"
+        );
+    }
+}
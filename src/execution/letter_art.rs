@@ -0,0 +1,349 @@
+/// An answer drawn as letters on a grid, as printed by e.g. 2016 day 8, 2019 days 8 and 11, 2021 day 13 and 2022 day 10.
+///
+/// The letters are read off the art in the 4x6 and 6x10 fonts Advent of Code uses, and checked like a string answer.
+/// The art itself is kept, and shown below the answer when a single part runs, or when a letter couldn't be recognised.
+/// `#` and `█` are lit, anything else is not. Letters that aren't recognised become `?`.
+///
+/// ```
+/// use aoc_handler::LetterArt;
+///
+/// let art = LetterArt::parse(
+///     "█..█.███\n\
+///      █..█..█.\n\
+///      ████..█.\n\
+///      █..█..█.\n\
+///      █..█..█.\n\
+///      █..█.███",
+/// );
+/// assert_eq!(art.letters(), "HI");
+/// assert!(art.is_recognised());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LetterArt {
+    pub(crate) letters: String,
+    pub(crate) art: String,
+}
+
+impl LetterArt {
+    /// Reads the letters off art drawn with `#` or `█`, one line per row.
+    pub fn parse(art: &str) -> Self {
+        let rows: Vec<Vec<bool>> = art
+            .lines()
+            .map(|line| line.chars().map(|c| matches!(c, '#' | '█')).collect())
+            .collect();
+        Self {
+            letters: recognise(&rows),
+            art: art.trim_end().to_owned(),
+        }
+    }
+
+    /// Reads the letters off a grid of lit pixels, e.g. the screen a solver simulated, one iterator per row.
+    pub fn from_grid<Row: IntoIterator<Item = bool>>(grid: impl IntoIterator<Item = Row>) -> Self {
+        let art: Vec<String> = grid
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|lit| if lit { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        Self::parse(&art.join("\n"))
+    }
+
+    pub fn letters(&self) -> &str {
+        &self.letters
+    }
+
+    pub fn art(&self) -> &str {
+        &self.art
+    }
+
+    /// Whether every letter was recognised.
+    pub fn is_recognised(&self) -> bool {
+        !self.letters.is_empty() && !self.letters.contains('?')
+    }
+}
+
+/// Cuts the art into letters at the fixed pitch of the font matching the art's height, and looks each of them up.
+/// Some letters are wide enough to touch their neighbours, so the empty columns between letters are only a fallback, for art that doesn't keep to the pitch.
+fn recognise(rows: &[Vec<bool>]) -> String {
+    let lit_row = |row: &&Vec<bool>| row.contains(&true);
+    let (Some(top), Some(bottom)) = (
+        rows.iter().position(|row| lit_row(&row)),
+        rows.iter().rposition(|row| lit_row(&row)),
+    ) else {
+        return String::new();
+    };
+    let rows = &rows[top..=bottom];
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |row: usize, column: usize| rows[row].get(column).copied().unwrap_or(false);
+    let lit_column = |column: &usize| (0..rows.len()).any(|row| lit(row, *column));
+
+    //? Trimmed to its lit columns, like the glyphs in the fonts
+    let glyph = |columns: std::ops::Range<usize>| {
+        let first = columns.clone().find(lit_column)?;
+        let last = columns.rev().find(lit_column)?;
+        Some(
+            (0..rows.len())
+                .map(|row| {
+                    (first..=last)
+                        .map(|column| if lit(row, column) { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>(),
+        )
+    };
+    let read = |glyph: Vec<String>| match rows.len() {
+        6 => lookup(SMALL_FONT, &glyph),
+        10 => lookup(LARGE_FONT, &glyph),
+        _ => '?',
+    };
+
+    let Some(first_lit) = (0..width).find(lit_column) else {
+        return String::new();
+    };
+    if let Some(pitch) = match rows.len() {
+        6 => Some(5),
+        10 => Some(8),
+        _ => None,
+    } {
+        //? The first letter may start with unlit columns, so its cell can begin a little before the first lit column
+        for start in (first_lit.saturating_sub(pitch - 1)..=first_lit).rev() {
+            let letters: String = (start..width)
+                .step_by(pitch)
+                .filter_map(|column| glyph(column..column + pitch))
+                .map(read)
+                .collect();
+            if !letters.contains('?') {
+                return letters;
+            }
+        }
+    }
+
+    let mut letters = String::new();
+    let mut start = None;
+    //? One column past the end, so that the last letter is closed like all others
+    for column in 0..=width {
+        match (start, lit_column(&column)) {
+            (None, true) => start = Some(column),
+            (Some(first), false) => {
+                letters.extend(glyph(first..column).map(read));
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    letters
+}
+
+fn lookup<const HEIGHT: usize>(font: &[(char, [&str; HEIGHT])], glyph: &[String]) -> char {
+    font.iter()
+        .find(|(_, rows)| rows.iter().eq(glyph))
+        .map_or('?', |(letter, _)| *letter)
+}
+
+//* Glyphs are trimmed to their lit columns, the way they are cut out of the art, and drawn at the start of their cell in the art.
+const SMALL_FONT: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+const LARGE_FONT: &[(char, [&str; 10])] = &[
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{LARGE_FONT, LetterArt, SMALL_FONT};
+
+    /// Draws a word the way Advent of Code does, each glyph at the start of a cell of the font's pitch.
+    fn render<const HEIGHT: usize>(
+        font: &[(char, [&str; HEIGHT])],
+        pitch: usize,
+        word: &[char],
+    ) -> String {
+        (0..HEIGHT)
+            .map(|row| {
+                word.iter()
+                    .flat_map(|letter| {
+                        let (_, glyph) = font
+                            .iter()
+                            .find(|(entry, _)| entry == letter)
+                            .expect("Letter isn't in the font");
+                        glyph[row].chars().chain(std::iter::repeat('.')).take(pitch)
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn assert_round_trips<const HEIGHT: usize>(font: &[(char, [&str; HEIGHT])], pitch: usize) {
+        let letters: Vec<char> = font.iter().map(|(letter, _)| *letter).collect();
+        for &letter in &letters {
+            let art = LetterArt::parse(&render(font, pitch, &[letter]));
+            assert_eq!(art.letters(), letter.to_string(), "\n{}", art.art());
+        }
+        for &first in &letters {
+            for &second in &letters {
+                let art = LetterArt::parse(&render(font, pitch, &[first, second]));
+                assert_eq!(art.letters(), format!("{first}{second}"), "\n{}", art.art());
+            }
+        }
+    }
+
+    #[test]
+    fn small_font_round_trips() {
+        assert_round_trips(SMALL_FONT, 5);
+    }
+
+    #[test]
+    fn large_font_round_trips() {
+        assert_round_trips(LARGE_FONT, 8);
+    }
+
+    #[test]
+    fn wide_letters_touching_their_neighbours_are_recognised() {
+        for word in ["YH", "EYE", "YYY"] {
+            let letters: Vec<char> = word.chars().collect();
+            assert_eq!(
+                LetterArt::parse(&render(SMALL_FONT, 5, &letters)).letters(),
+                word
+            );
+        }
+    }
+
+    #[test]
+    fn art_off_the_pitch_is_split_at_empty_columns() {
+        //? I and H, one column apart, so that the first cell catches the edge of the H
+        let art = LetterArt::parse(
+            "###.#..#\n\
+             .#..#..#\n\
+             .#..####\n\
+             .#..#..#\n\
+             .#..#..#\n\
+             ###.#..#",
+        );
+        assert_eq!(art.letters(), "IH");
+    }
+}
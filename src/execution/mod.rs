@@ -1,6 +1,8 @@
 use crate::{
-    execution::result::{CheckedRunReturn, DayReturn, PartReturn, Unchecked},
+    context::Scope,
+    execution::result::{CaseReturn, CheckedRunReturn, DayReturn, PartReturn, Unchecked},
     input_handler::InputError,
+    solver::{Parser, PartSolver, SolverPart},
     time_key::{
         Day, Part, PartInternal, TimeDetailDay, TimeDetailDayAndPart, TimeDetailNone, TimeKey,
     },
@@ -8,10 +10,16 @@ use crate::{
 
 pub mod solution_part;
 pub use solution_part::SolutionPart;
+mod letter_art;
+pub use letter_art::LetterArt;
 mod test_input;
-pub use test_input::TestInput;
+pub use test_input::{TestCase, TestInput, resolve};
 pub mod result;
-use result::{AcquisitionError, CheckReturn, PartOutput, RunReturn};
+use result::{AcquisitionError, CheckReturn, PartOutcome, PartOutput, RunReturn};
+mod fuzz;
+mod prepared_input;
+pub use prepared_input::DayInputs;
+use prepared_input::PreparedInput;
 
 #[derive(Debug, Clone, Copy)]
 pub enum Request {
@@ -57,12 +65,23 @@ impl super::Handler<'_> {
         request: Request,
         time_key: TimeKey<TimeDetailDay>,
     ) -> DayReturn {
-        //? Persistent access to the run input, since it's shared between parts, and getting it isn't free
-        let run_input = std::cell::OnceCell::new();
+        self.execute_day_observed(request, time_key, |_| ())
+    }
 
-        time_key
-            .both()
-            .map(|key| self.execute_part(request, key, &run_input))
+    /// Same as [`Self::execute_day`], but announces each part before executing it, so that progress can be shown.
+    pub(super) fn execute_day_observed(
+        &self,
+        request: Request,
+        time_key: TimeKey<TimeDetailDay>,
+        mut before_part: impl FnMut(TimeKey<TimeDetailDayAndPart>),
+    ) -> DayReturn {
+        //? Persistent access to the inputs, since they're shared between parts, and fetching and parsing them isn't free
+        let inputs = DayInputs::default();
+
+        time_key.both().map(|key| {
+            before_part(key);
+            self.execute_part(request, key, &inputs)
+        })
     }
 
     pub(super) fn execute_most_recent_part(
@@ -71,10 +90,10 @@ impl super::Handler<'_> {
         time_key: TimeKey<TimeDetailNone>,
     ) -> (Day, Part, PartReturn) {
         let mut parts_rev = time_key.iterate().rev().flat_map(move |key| {
-            let run_input = std::cell::OnceCell::new();
+            let inputs = DayInputs::default();
             key.both().into_iter().rev().map(move |key| {
                 let (_, day, part) = key.to_primitive();
-                (day, part, self.execute_part(request, key, &run_input))
+                (day, part, self.execute_part(request, key, &inputs))
             })
         });
 
@@ -87,8 +106,17 @@ impl super::Handler<'_> {
         &self,
         request: Request,
         time_key: TimeKey<TimeDetailDayAndPart>,
-        run_input: &std::cell::OnceCell<Result<String, InputError>>,
+        inputs: &DayInputs,
     ) -> PartReturn {
+        capture_part(|| self.execute_part_outcome(request, time_key, inputs))
+    }
+
+    fn execute_part_outcome(
+        &self,
+        request: Request,
+        time_key: TimeKey<TimeDetailDayAndPart>,
+        inputs: &DayInputs,
+    ) -> Result<PartOutcome, AcquisitionError> {
         /*
         Flow:
         Top Level:
@@ -107,27 +135,25 @@ impl super::Handler<'_> {
         }
         .ok_or(AcquisitionError::NotMapped)?;
 
-        let solver_part = match time_key.detail.part {
+        let parser = solver.parser.as_ref();
+        let part = time_key.detail.part;
+        let mut solver_part = match part {
             PartInternal::One => solver.part_1,
             PartInternal::Two => solver.part_2,
         };
 
-        //* Checking
-
-        let check_return = match solver_part.test_input {
-            TestInput::None => CheckReturn::Unchecked(Unchecked::MissingInput),
-            TestInput::Empty => match solver_part.test_answer {
-                SolutionPart::Unimplemented => CheckReturn::Unchecked(Unchecked::Elided),
-                sp => CheckReturn::Unchecked(Unchecked::ElideMismatch(sp)),
-            },
-            TestInput::Input(i) => {
-                let ret = (solver_part.solver)(&i);
-                match ret.check_against(&solver_part.test_answer) {
-                    None => return Err(AcquisitionError::Unimplemented),
-                    Some(check_return) => check_return,
-                }
+        //? Parts that didn't provide a test fall back to example files in the project, if there are any
+        if matches!(solver_part.test_input, TestInput::None)
+            && let Some((test_input, test_answer)) = TestInput::by_convention(time_key)
+        {
+            solver_part.test_input = test_input;
+            if matches!(solver_part.test_answer, SolutionPart::Unimplemented) {
+                solver_part.test_answer = test_answer;
             }
-        };
+        }
+
+        //* Checking
+        let check_return = check_part(&solver_part, parser, part, inputs)?;
 
         //? Return: Only checking
         if matches!(request, Request::Check) {
@@ -135,62 +161,534 @@ impl super::Handler<'_> {
             if matches!(
                 check_return,
                 CheckReturn::Unchecked(Unchecked::MissingInput)
-            ) && let Ok(input) =
-                run_input.get_or_init(|| self.input.get_day_input(time_key.deref()))
-                && matches!((solver_part.solver)(input), SolutionPart::Unimplemented)
+            ) && let Ok(input) = inputs
+                .run
+                .get_or_init(|| self.prepare_run_input(parser, time_key))
+                && matches!(
+                    input.run(&solver_part.solver, part).solution_part,
+                    SolutionPart::Unimplemented
+                )
             {
                 return Err(AcquisitionError::Unimplemented);
             }
-            return Ok(PartOutput::Checked(check_return));
+            return Ok(PartOutcome::Checked(check_return));
         }
 
         //* running
         //? Return: Check failed
-        if let CheckReturn::Failed(e) = check_return {
-            return Ok(PartOutput::CheckedAndRan(CheckedRunReturn::CheckFailed(e)));
+        if check_return.failed() {
+            let checked_run_return = match check_return {
+                CheckReturn::Failed(e) => CheckedRunReturn::CheckFailed(e),
+                CheckReturn::Cases(cases) | CheckReturn::Implementations(cases) => {
+                    CheckedRunReturn::CasesFailed(cases)
+                }
+                CheckReturn::Passed(..) | CheckReturn::Unchecked(_) | CheckReturn::Fuzzed(_) => {
+                    unreachable!()
+                }
+            };
+            return Ok(PartOutcome::CheckedAndRan(checked_run_return));
         }
 
-        let checked_run_return = run_input
-            .get_or_init(|| self.input.get_day_input(time_key.deref()))
+        let checked_run_return = inputs
+            .run
+            .get_or_init(|| self.prepare_run_input(parser, time_key))
             .as_ref()
-            .map(|input| {
-                let time_start = std::time::Instant::now();
-                let solution_part = (solver_part.solver)(input);
-                let time_taken = time_start.elapsed();
-
-                RunReturn {
-                    solution_part,
-                    time_taken,
-                }
-            })
+            .map(|input| run_part(&solver_part, part, input))
             .map_or_else(
                 |input_error| CheckedRunReturn::RunFailed(input_error.clone()),
-                |run_return| match check_return {
-                    CheckReturn::Passed => CheckedRunReturn::Ok(run_return),
-                    CheckReturn::Unchecked(uc) => CheckedRunReturn::Unchecked {
-                        reason: uc,
-                        ret: run_return,
-                    },
-                    CheckReturn::Failed(_) => unreachable!(),
+                |run_return| {
+                    run_return.map_or_else(CheckedRunReturn::Disagreement, |run_return| {
+                        combine_check_and_run(check_return, run_return)
+                    })
                 },
             );
 
         match checked_run_return {
-            CheckedRunReturn::Ok(RunReturn {
-                solution_part: SolutionPart::Unimplemented,
-                time_taken: _,
-            }) //Test passed, but rr is unimplemented? Weird
+            CheckedRunReturn::Ok {
+                check: _,
+                ret:
+                    RunReturn {
+                        solution_part: SolutionPart::Unimplemented,
+                        ..
+                    },
+            } //Test passed, but rr is unimplemented? Weird
             | CheckedRunReturn::Unchecked {
                 reason: _,
                 ret:
                     RunReturn {
                         solution_part: SolutionPart::Unimplemented,
-                        time_taken: _,
+                        ..
                     },
             } => return Err(AcquisitionError::Unimplemented),
             _ => (),
         }
 
-        Ok(PartOutput::CheckedAndRan(checked_run_return))
+        Ok(PartOutcome::CheckedAndRan(checked_run_return))
+    }
+
+    /// Runs a part on a user supplied input instead of the real one, skipping the tests.
+    /// If an expected answer is provided, the result is checked against it.
+    pub(super) fn execute_part_on(
+        &self,
+        time_key: TimeKey<TimeDetailDayAndPart>,
+        input: String,
+        expected: &SolutionPart,
+    ) -> PartReturn {
+        capture_part(|| self.execute_part_on_outcome(time_key, input, expected))
+    }
+
+    fn execute_part_on_outcome(
+        &self,
+        time_key: TimeKey<TimeDetailDayAndPart>,
+        input: String,
+        expected: &SolutionPart,
+    ) -> Result<PartOutcome, AcquisitionError> {
+        let solver = {
+            let (year, day, _) = time_key.to_primitive();
+            (self.mapper)(year, day)
+        }
+        .ok_or(AcquisitionError::NotMapped)?;
+
+        let part = time_key.detail.part;
+        let solver_part = match part {
+            PartInternal::One => &solver.part_1,
+            PartInternal::Two => &solver.part_2,
+        };
+
+        let input = PreparedInput::new(solver.parser.as_ref(), input, Scope::default());
+
+        let run_return = match run_part(solver_part, part, &input) {
+            Ok(run_return) => run_return,
+            Err(run_returns) => {
+                return Ok(PartOutcome::CheckedAndRan(CheckedRunReturn::Disagreement(
+                    run_returns,
+                )));
+            }
+        };
+
+        //? Without an expected answer, the check was deliberately skipped, same as an elided test
+        let checked_run_return = match run_return.solution_part.clone().check_against(expected) {
+            None => return Err(AcquisitionError::Unimplemented),
+            Some(CheckReturn::Failed(ce)) => CheckedRunReturn::CheckFailed(ce),
+            Some(check @ CheckReturn::Passed(..)) => CheckedRunReturn::Ok {
+                check: Box::new(check),
+                ret: run_return,
+            },
+            Some(_) => CheckedRunReturn::Unchecked {
+                reason: Unchecked::Elided,
+                ret: run_return,
+            },
+        };
+
+        Ok(PartOutcome::CheckedAndRan(checked_run_return))
+    }
+
+    fn prepare_run_input(
+        &self,
+        parser: Option<&Parser>,
+        time_key: TimeKey<TimeDetailDayAndPart>,
+    ) -> Result<PreparedInput, InputError> {
+        self.day_input(time_key.deref())
+            .map(|input| PreparedInput::new(parser, input, Scope::default()))
+    }
+
+    /// The real input of a day, from the cache only in offline mode.
+    pub(crate) fn day_input(&self, time_key: TimeKey<TimeDetailDay>) -> Result<String, InputError> {
+        //? Offline, the client isn't even created, since that may prompt for a session token
+        if self.offline {
+            crate::input_handler::get_cached_day_input(time_key)
+        } else {
+            self.input.get_day_input(time_key)
+        }
+    }
+}
+
+/// Attaches everything logged while producing the outcome of a part, so it can be shown alongside it instead of interleaving with the results.
+fn capture_part(f: impl FnOnce() -> Result<PartOutcome, AcquisitionError>) -> PartReturn {
+    let (outcome, captured) = crate::context::capture_log(f);
+    outcome.map(|outcome| PartOutput { outcome, captured })
+}
+
+/// Checks every implementation of a part against its main test and all named test cases.
+fn check_part(
+    solver_part: &SolverPart,
+    parser: Option<&Parser>,
+    part: PartInternal,
+    inputs: &DayInputs,
+) -> Result<CheckReturn, AcquisitionError> {
+    let check_implementation = |solver: &PartSolver| {
+        let check_return = check_case(
+            &solver_part.test_input,
+            &solver_part.test_answer,
+            |i, scope| {
+                //? Both parts usually share the test input, in which case it's only parsed once
+                let prepared = inputs
+                    .test
+                    .get_or_init(|| PreparedInput::new(parser, i.clone(), scope.clone()));
+                let local;
+                let prepared = if prepared.is_for(&i, &scope) {
+                    prepared
+                } else {
+                    local = PreparedInput::new(parser, i, scope);
+                    &local
+                };
+                prepared.run(solver, part).solution_part
+            },
+        )?;
+
+        //? Additional named cases get reported individually, alongside the main test if there is one
+        if solver_part.test_cases.is_empty() {
+            Ok(check_return)
+        } else {
+            check_cases(check_return, &solver_part.test_cases, |i, scope| {
+                PreparedInput::new(parser, i, scope)
+                    .run(solver, part)
+                    .solution_part
+            })
+            .map(CheckReturn::Cases)
+        }
+    };
+
+    let check_return = check_implementation(&solver_part.solver)?;
+    if solver_part.alternatives.is_empty() {
+        return Ok(check_return);
+    }
+
+    //? Alternatives that turn out to be unimplemented are shown as skipped, rather than failing the whole part
+    let implementations =
+        std::iter::once(CaseReturn {
+            name: PRIMARY_NAME.to_string(),
+            check_return,
+        })
+        .chain(solver_part.alternatives.iter().map(|(name, solver)| {
+            CaseReturn {
+                name: name.clone(),
+                check_return: check_implementation(solver)
+                    .unwrap_or(CheckReturn::Unchecked(Unchecked::Unimplemented)),
+            }
+        }))
+        .collect();
+    Ok(CheckReturn::Implementations(implementations))
+}
+
+/// Runs a single test case through `solve` and compares it to the expected answer.
+fn check_case(
+    test_input: &TestInput,
+    test_answer: &SolutionPart,
+    solve: impl FnOnce(String, Scope) -> SolutionPart,
+) -> Result<CheckReturn, AcquisitionError> {
+    Ok(match test_input {
+        TestInput::None => CheckReturn::Unchecked(Unchecked::MissingInput),
+        TestInput::Unreadable(path) => {
+            CheckReturn::Unchecked(Unchecked::UnreadableInput(path.clone()))
+        }
+        TestInput::Empty => match test_answer {
+            SolutionPart::Unimplemented => CheckReturn::Unchecked(Unchecked::Elided),
+            sp => CheckReturn::Unchecked(Unchecked::ElideMismatch(sp.clone())),
+        },
+        TestInput::Input(i, params) => solve(
+            i.clone(),
+            Scope {
+                is_test: true,
+                params: params.clone(),
+            },
+        )
+        .check_against(test_answer)
+        .ok_or(AcquisitionError::Unimplemented)?,
+    })
+}
+
+/// Checks all named test cases of a part, with the main test leading them if one was provided.
+fn check_cases(
+    main_check_return: CheckReturn,
+    test_cases: &[TestCase],
+    solve: impl Fn(String, Scope) -> SolutionPart,
+) -> Result<Vec<CaseReturn>, AcquisitionError> {
+    let main_case = match main_check_return {
+        CheckReturn::Unchecked(Unchecked::MissingInput | Unchecked::Elided) => None,
+        check_return => Some(CaseReturn {
+            name: "Example".to_string(),
+            check_return,
+        }),
+    };
+
+    main_case
+        .into_iter()
+        .map(Ok)
+        .chain(test_cases.iter().map(|case| {
+            Ok(CaseReturn {
+                name: case.name.clone(),
+                check_return: check_case(&case.input, &case.answer, &solve)?,
+            })
+        }))
+        .collect()
+}
+
+/// Runs every implementation of a part on the real input.
+/// If there are alternatives, they have to agree with the primary implementation, otherwise all results are returned as a disagreement.
+fn run_part(
+    solver_part: &SolverPart,
+    part: PartInternal,
+    input: &PreparedInput,
+) -> Result<RunReturn, Vec<(String, RunReturn)>> {
+    let run_return = input.run(&solver_part.solver, part);
+    //? An unimplemented part is reported as such, whatever its alternatives have to say
+    if matches!(run_return.solution_part, SolutionPart::Unimplemented) {
+        return Ok(run_return);
+    }
+
+    let alternatives = solver_part
+        .alternatives
+        .iter()
+        .map(|(name, solver)| (name.clone(), input.run(solver, part)))
+        .collect::<Vec<_>>();
+
+    //? Alternatives that turn out to be unimplemented are skipped, as with checking
+    let implemented = |rr: &RunReturn| !matches!(rr.solution_part, SolutionPart::Unimplemented);
+    if alternatives
+        .iter()
+        .filter(|(_, rr)| implemented(rr))
+        .all(|(_, rr)| rr.solution_part.agrees_with(&run_return.solution_part))
+    {
+        Ok(RunReturn {
+            alternative_times: alternatives
+                .into_iter()
+                .map(|(name, rr)| (name, implemented(&rr).then_some(rr.time_taken)))
+                .collect(),
+            ..run_return
+        })
+    } else {
+        Err(std::iter::once((PRIMARY_NAME.to_string(), run_return))
+            .chain(alternatives.into_iter().filter(|(_, rr)| implemented(rr)))
+            .collect())
+    }
+}
+
+fn combine_check_and_run(check_return: CheckReturn, run_return: RunReturn) -> CheckedRunReturn {
+    match check_return.clone().summarise() {
+        CheckReturn::Passed(..) => CheckedRunReturn::Ok {
+            check: Box::new(check_return),
+            ret: run_return,
+        },
+        CheckReturn::Unchecked(uc) => CheckedRunReturn::Unchecked {
+            reason: uc,
+            ret: run_return,
+        },
+        //Failures never get to run, summaries aren't composite and fuzzing only ever checks
+        CheckReturn::Failed(_)
+        | CheckReturn::Cases(_)
+        | CheckReturn::Implementations(_)
+        | CheckReturn::Fuzzed(_) => unreachable!(),
+    }
+}
+
+//? Label of the implementation provided directly, as opposed to the named alternatives
+const PRIMARY_NAME: &str = "Primary";
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::{DayInputs, PreparedInput, Request};
+    use crate::{
+        Context, Handler, Implementation, Results, Solver, aoc_println,
+        context::{Scope, capture_log},
+        execution::result::{
+            AcquisitionError, CheckedRunReturn, PartOutcome, PartOutput, PartReturn, RunReturn,
+        },
+        time_key::{TimeDetailDayAndPart, TimeKey},
+    };
+
+    #[test]
+    fn captured_output_stays_with_its_part() {
+        let mapper = |_year, _day| {
+            Some(
+                Solver::new(
+                    |input: &str| {
+                        aoc_println!("part one on {input}");
+                        1
+                    },
+                    |input: &str| {
+                        Context::log(format!("part two on {input}"));
+                        2
+                    },
+                )
+                .with_part_1_test("a", 1)
+                .with_part_2_test("b", 2),
+            )
+        };
+        let handler = Handler::new(&mapper).with_offline(true);
+
+        let (report, outside) = capture_log(|| handler.reports().check_day(2015, 1));
+        let captured: Vec<_> = report
+            .unwrap()
+            .parts()
+            .map(|(_, _, _, part_return)| part_return.as_ref().unwrap().captured.clone())
+            .collect();
+
+        assert_eq!(captured, ["part one on a", "part two on b"]);
+        assert_eq!(outside, "");
+    }
+
+    fn run_on(solver: impl Fn() -> Solver) -> PartReturn {
+        let mapper = |_year, _day| Some(solver());
+        let report = Handler::new(&mapper)
+            .with_offline(true)
+            .reports()
+            .run_part_on_input(2015, 1, 1, "input", ())
+            .unwrap();
+        let Results::Part(.., part_return) = report.results() else {
+            panic!("A single part should report a part");
+        };
+        part_return.clone()
+    }
+
+    #[test]
+    fn unimplemented_primaries_are_not_compared() {
+        let solver = || {
+            Solver::new(|_: &str| (), |_: &str| ())
+                .with_part_1_alternatives([Implementation::new("Naive", |_: &str| 1)])
+        };
+        assert!(matches!(
+            run_on(solver),
+            Err(AcquisitionError::Unimplemented)
+        ));
+    }
+
+    #[test]
+    fn disagreeing_alternatives_report_every_answer() {
+        let solver = || {
+            Solver::new(|_: &str| 1, |_: &str| ()).with_part_1_alternatives([
+                Implementation::new("Naive", |_: &str| 2),
+                Implementation::new("Unfinished", |_: &str| ()),
+            ])
+        };
+        let Ok(PartOutput {
+            outcome: PartOutcome::CheckedAndRan(CheckedRunReturn::Disagreement(run_returns)),
+            ..
+        }) = run_on(solver)
+        else {
+            panic!("Expected a disagreement");
+        };
+        let answers: Vec<_> = run_returns
+            .iter()
+            .map(|(name, rr)| (name.as_str(), rr.solution_part.to_string()))
+            .collect();
+        assert_eq!(
+            answers,
+            [("Primary", "1".to_owned()), ("Naive", "2".to_owned())]
+        );
+    }
+
+    #[test]
+    fn unimplemented_alternatives_are_skipped() {
+        let solver = || {
+            Solver::new(|_: &str| 1, |_: &str| ()).with_part_1_alternatives([
+                Implementation::new("Naive", |_: &str| 1),
+                Implementation::new("Unfinished", |_: &str| ()),
+            ])
+        };
+        let part_return = run_on(solver);
+        let run_return = part_return
+            .as_ref()
+            .unwrap()
+            .outcome
+            .run_return()
+            .expect("Agreeing alternatives should run");
+        let skipped: Vec<_> = run_return
+            .alternative_times
+            .iter()
+            .map(|(name, time)| (name.as_str(), time.is_none()))
+            .collect();
+        assert_eq!(skipped, [("Naive", false), ("Unfinished", true)]);
+    }
+
+    /// Checks and runs both parts of a day, with the real input in place of the cached one.
+    fn check_and_run_day(solver: impl Fn() -> Solver, real_input: &str) -> [PartReturn; 2] {
+        let inputs = DayInputs::default();
+        let prepared = PreparedInput::new(
+            solver().parser.as_ref(),
+            real_input.to_owned(),
+            Scope::default(),
+        );
+        assert!(inputs.run.set(Ok(prepared)).is_ok());
+
+        let mapper = |_year, _day| Some(solver());
+        let handler = Handler::new(&mapper).with_offline(true);
+        [1, 2].map(|part| {
+            let time_key = TimeKey::<TimeDetailDayAndPart>::new(2015, 1, part).unwrap();
+            handler.execute_part(Request::CheckAndRun, time_key, &inputs)
+        })
+    }
+
+    fn run_return(part_return: &PartReturn) -> &RunReturn {
+        part_return
+            .as_ref()
+            .unwrap()
+            .outcome
+            .run_return()
+            .expect("The part should have run")
+    }
+
+    #[test]
+    fn parse_steps_run_once_per_input() {
+        let parses = Rc::new(Cell::new(0));
+        let solver = || {
+            let parses = parses.clone();
+            Solver::parsed(
+                move |input: &str| {
+                    parses.set(parses.get() + 1);
+                    input.len()
+                },
+                |len: &usize| *len,
+                |len: &usize| len * 2,
+            )
+            .with_part_1_test("ab", 2)
+            .with_part_2_test("ab", 4)
+        };
+
+        let parts = check_and_run_day(solver, "abc");
+
+        assert_eq!(parses.get(), 2); //Once for the shared test input, once for the real one
+        for (part_return, answer) in parts.iter().zip(["3", "6"]) {
+            let run_return = run_return(part_return);
+            assert_eq!(run_return.solution_part.to_string(), answer);
+            assert!(run_return.parse_time.is_some());
+        }
+    }
+
+    #[test]
+    fn combined_solvers_run_once_per_input() {
+        let runs = Rc::new(Cell::new(0));
+        let solver = || {
+            let runs = runs.clone();
+            Solver::combined(move |input: &str| {
+                runs.set(runs.get() + 1);
+                (input.len(), input.len() * 2)
+            })
+            .with_part_1_test("ab", 2)
+            .with_part_2_test("ab", 4)
+        };
+
+        let parts = check_and_run_day(solver, "abc");
+
+        assert_eq!(runs.get(), 2); //Once for the shared test input, once for the real one
+        for (part_return, answer) in parts.iter().zip(["3", "6"]) {
+            let run_return = run_return(part_return);
+            assert_eq!(run_return.solution_part.to_string(), answer);
+            assert!(run_return.shared_time);
+        }
+    }
+
+    #[test]
+    fn nested_captures_do_not_leak_into_each_other() {
+        let (((), inner), outer) = capture_log(|| {
+            aoc_println!("before");
+            let inner = capture_log(|| aoc_println!("inner"));
+            aoc_println!("after");
+            inner
+        });
+        assert_eq!(inner, "inner");
+        assert_eq!(outer, "before\nafter");
     }
 }
@@ -0,0 +1,100 @@
+use crate::{
+    context::Scope,
+    execution::{SolutionPart, result::RunReturn},
+    input_handler::InputError,
+    solver::{Parser, PartSolver},
+    time_key::PartInternal,
+};
+
+/// An input, alongside its parsed form if the solver provides a parse step.
+pub struct PreparedInput {
+    raw: String,
+    scope: Scope,
+    parsed: Option<Box<dyn std::any::Any>>,
+    parse_time: Option<std::time::Duration>,
+    //? Output of a combined solver, so the second part doesn't have to run it again
+    combined: std::cell::OnceCell<((SolutionPart, SolutionPart), std::time::Duration)>,
+}
+
+impl PreparedInput {
+    pub fn new(parser: Option<&Parser>, raw: String, scope: Scope) -> Self {
+        match parser {
+            None => Self {
+                raw,
+                scope,
+                parsed: None,
+                parse_time: None,
+                combined: std::cell::OnceCell::new(),
+            },
+            Some(parse) => {
+                let time_start = std::time::Instant::now();
+                let parsed = scope.enter(|| parse(&raw));
+                let time_taken = time_start.elapsed();
+
+                Self {
+                    raw,
+                    scope,
+                    parsed: Some(parsed),
+                    parse_time: Some(time_taken),
+                    combined: std::cell::OnceCell::new(),
+                }
+            }
+        }
+    }
+
+    /// Whether this was prepared from the given input, so that it can be reused.
+    pub fn is_for(&self, raw: &str, scope: &Scope) -> bool {
+        self.raw == raw && self.scope == *scope
+    }
+
+    /// What the solver actually gets handed: The parsed input if there is one, otherwise the raw `String`.
+    fn get(&self) -> &dyn std::any::Any {
+        match &self.parsed {
+            Some(parsed) => parsed.as_ref(),
+            None => &self.raw,
+        }
+    }
+
+    /// Runs and times the given part on this input.
+    pub fn run(&self, solver: &PartSolver, part: PartInternal) -> RunReturn {
+        let (solution_part, time_taken, shared_time) = match solver {
+            PartSolver::Separate(solver) => {
+                let time_start = std::time::Instant::now();
+                let solution_part = self.scope.enter(|| solver(self.get()));
+                (solution_part, time_start.elapsed(), false)
+            }
+            PartSolver::Raw(solver) => {
+                let time_start = std::time::Instant::now();
+                let solution_part = self.scope.enter(|| solver(&self.raw));
+                (solution_part, time_start.elapsed(), false)
+            }
+            PartSolver::Combined(solver) => {
+                let ((part_1, part_2), time_taken) = self.combined.get_or_init(|| {
+                    let time_start = std::time::Instant::now();
+                    let solution_parts = self.scope.enter(|| solver(self.get()));
+                    (solution_parts, time_start.elapsed())
+                });
+                let solution_part = match part {
+                    PartInternal::One => part_1,
+                    PartInternal::Two => part_2,
+                };
+                (solution_part.clone(), *time_taken, true)
+            }
+        };
+
+        RunReturn {
+            solution_part,
+            time_taken,
+            shared_time,
+            parse_time: self.parse_time,
+            alternative_times: Vec::new(),
+        }
+    }
+}
+
+/// Inputs shared between the parts of a single day, so that each is fetched and parsed at most once.
+#[derive(Default)]
+pub struct DayInputs {
+    pub run: std::cell::OnceCell<Result<PreparedInput, InputError>>,
+    pub test: std::cell::OnceCell<PreparedInput>,
+}
@@ -1,4 +1,5 @@
-use super::solution_part::{CheckError, SolutionPart};
+pub use super::solution_part::CheckError;
+use super::solution_part::SolutionPart;
 /*
 General:
     Not mapped (no solver available)
@@ -33,30 +34,183 @@ pub enum AcquisitionError {
 }
 
 #[derive(Debug, Clone)]
-pub enum PartOutput {
+pub struct PartOutput {
+    pub outcome: PartOutcome,
+    pub captured: String, //Anything the solver logged while it was checked and run
+}
+
+#[derive(Debug, Clone)]
+pub enum PartOutcome {
     Checked(CheckReturn),
     CheckedAndRan(CheckedRunReturn),
 }
 
+impl PartOutcome {
+    /// The result of checking against the test input, unless the check already failed as part of a run.
+    pub fn check_return(&self) -> Option<&CheckReturn> {
+        match self {
+            Self::Checked(cr) => Some(cr),
+            Self::CheckedAndRan(_) => None,
+        }
+    }
+
+    /// The result of running on the real input, if it got that far.
+    pub fn run_return(&self) -> Option<&RunReturn> {
+        match self {
+            Self::Checked(_) => None,
+            Self::CheckedAndRan(crr) => crr.run_return(),
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        match self {
+            Self::Checked(cr) => cr.failed(),
+            Self::CheckedAndRan(crr) => matches!(
+                crr,
+                CheckedRunReturn::CheckFailed(_)
+                    | CheckedRunReturn::CasesFailed(_)
+                    | CheckedRunReturn::Disagreement(_)
+                    | CheckedRunReturn::RunFailed(_)
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum CheckReturn {
-    Passed,
+    Passed(SolutionPart, SolutionPart), //The actual and expected answer, which may differ in form, e.g. letter art against a string
     Unchecked(Unchecked),
     Failed(CheckError),
+    Cases(Vec<CaseReturn>), //Multiple named test cases, each with their own result
+    Implementations(Vec<CaseReturn>), //Multiple named implementations, each with their own result
+    Fuzzed(FuzzReturn),     //Implementations compared on generated inputs
+}
+
+#[derive(Debug, Clone)]
+pub enum FuzzReturn {
+    Agreed {
+        runs: usize,
+    },
+    Disagreed {
+        input: String, //Already shrunk
+        saved_to: Result<std::path::PathBuf, crate::input_handler::InputError>,
+        answers: Vec<(String, SolutionPart)>,
+    },
+}
+
+///A named check result, either of a single test case or of a whole implementation
+#[derive(Debug, Clone)]
+pub struct CaseReturn {
+    pub name: String,
+    pub check_return: CheckReturn,
+}
+
+impl CaseReturn {
+    pub fn failed(&self) -> bool {
+        self.check_return.failed()
+    }
+}
+
+impl CheckReturn {
+    pub fn failed(&self) -> bool {
+        match self {
+            Self::Failed(_) | Self::Fuzzed(FuzzReturn::Disagreed { .. }) => true,
+            Self::Cases(cases) | Self::Implementations(cases) => {
+                cases.iter().any(CaseReturn::failed)
+            }
+            Self::Passed(..) | Self::Unchecked(_) | Self::Fuzzed(FuzzReturn::Agreed { .. }) => {
+                false
+            }
+        }
+    }
+
+    /// Collapses a check without failures into a single result.
+    /// It only passed if nothing went unchecked, deliberately elided tests aside.
+    /// Passes alongside unchecked cases are partial, and without any pass the first reason it went unchecked stands for the whole.
+    /// A full pass is represented by the first case that passed.
+    pub(crate) fn summarise(self) -> Self {
+        let is_pass = |summary: &Self| {
+            matches!(
+                summary,
+                Self::Passed(..) | Self::Fuzzed(FuzzReturn::Agreed { .. })
+            )
+        };
+        match self {
+            Self::Cases(cases) | Self::Implementations(cases) => {
+                let summaries = cases
+                    .into_iter()
+                    .map(|case| case.check_return.summarise())
+                    .collect::<Vec<_>>();
+                let (mut passed, mut unchecked) = (0, 0);
+                for summary in &summaries {
+                    match summary {
+                        pass if is_pass(pass) => passed += 1,
+                        Self::Unchecked(Unchecked::Elided | Unchecked::Unimplemented) => (),
+                        Self::Unchecked(Unchecked::Partial {
+                            passed: p,
+                            unchecked: u,
+                        }) => {
+                            passed += p;
+                            unchecked += u;
+                        }
+                        _ => unchecked += 1,
+                    }
+                }
+                match (passed, unchecked) {
+                    (0, _) => summaries
+                        .into_iter()
+                        .next()
+                        .unwrap_or(Self::Unchecked(Unchecked::MissingInput)),
+                    (_, 0) => summaries
+                        .into_iter()
+                        .find(is_pass)
+                        .expect("Internal error: Counted a pass that isn't there."),
+                    (passed, unchecked) => {
+                        Self::Unchecked(Unchecked::Partial { passed, unchecked })
+                    }
+                }
+            }
+            check_return => check_return,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum CheckedRunReturn {
-    Ok(RunReturn),
-    Unchecked { reason: Unchecked, ret: RunReturn },
+    Ok {
+        check: Box<CheckReturn>,
+        ret: RunReturn,
+    }, //The check that passed, before it was summarised
+    Unchecked {
+        reason: Unchecked,
+        ret: RunReturn,
+    },
     CheckFailed(CheckError),
+    CasesFailed(Vec<CaseReturn>),
+    Disagreement(Vec<(String, RunReturn)>), //Alternative implementations returned different answers
     RunFailed(crate::input_handler::InputError),
 }
 
+impl CheckedRunReturn {
+    /// The result of running on the real input, if the check didn't prevent it.
+    pub fn run_return(&self) -> Option<&RunReturn> {
+        match self {
+            Self::Ok { ret: rr, .. } | Self::Unchecked { ret: rr, .. } => Some(rr),
+            Self::CheckFailed(_)
+            | Self::CasesFailed(_)
+            | Self::Disagreement(_)
+            | Self::RunFailed(_) => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RunReturn {
     pub solution_part: SolutionPart,
     pub time_taken: std::time::Duration,
+    pub shared_time: bool, //The time was spent producing both parts at once
+    pub parse_time: Option<std::time::Duration>, //Only present if the solver has a separate parse step
+    pub alternative_times: Vec<(String, Option<std::time::Duration>)>, //Alternative implementations that agreed with this one, None if skipped
 }
 
 #[derive(Debug, Clone)]
@@ -65,9 +219,19 @@ pub enum Unchecked {
     ElideMismatch(SolutionPart),
     MissingInput,
     MissingOuput(SolutionPart),
+    NothingToCompare, //Fuzzing needs alternative implementations to compare against
+    UnreadableInput(std::path::PathBuf),
+    Partial { passed: usize, unchecked: usize }, //Some cases passed, others had nothing to check against
+    Unimplemented, //An alternative implementation returned (), and is skipped
 }
 
 impl PartialEq for PartOutput {
+    fn eq(&self, other: &Self) -> bool {
+        self.outcome == other.outcome && self.captured == other.captured
+    }
+}
+
+impl PartialEq for PartOutcome {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Checked(a), Self::Checked(b)) => a == b,
@@ -80,7 +244,7 @@ impl PartialEq for CheckReturn {
     fn eq(&self, other: &Self) -> bool {
         matches!(
             (self, other),
-            (Self::Passed, Self::Passed)
+            (Self::Passed(..), Self::Passed(..))
                 | (
                     Self::Unchecked(Unchecked::MissingInput),
                     Self::Unchecked(Unchecked::MissingInput)
@@ -88,3 +252,73 @@ impl PartialEq for CheckReturn {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{CaseReturn, CheckReturn, SolutionPart, Unchecked};
+
+    fn passed() -> CheckReturn {
+        CheckReturn::Passed(SolutionPart::from(42), SolutionPart::from(42))
+    }
+
+    fn cases(check_returns: impl IntoIterator<Item = CheckReturn>) -> CheckReturn {
+        CheckReturn::Cases(
+            check_returns
+                .into_iter()
+                .enumerate()
+                .map(|(i, check_return)| CaseReturn {
+                    name: format!("case {i}"),
+                    check_return,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn summarise_passes_only_if_nothing_is_unchecked() {
+        assert!(matches!(
+            cases([passed(), passed()]).summarise(),
+            CheckReturn::Passed(..)
+        ));
+        assert!(matches!(
+            cases([passed(), CheckReturn::Unchecked(Unchecked::Elided)]).summarise(),
+            CheckReturn::Passed(..)
+        ));
+        assert!(matches!(
+            cases([
+                passed(),
+                CheckReturn::Unchecked(Unchecked::MissingInput),
+                CheckReturn::Unchecked(Unchecked::NothingToCompare),
+            ])
+            .summarise(),
+            CheckReturn::Unchecked(Unchecked::Partial {
+                passed: 1,
+                unchecked: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn summarise_without_passes_keeps_the_first_reason() {
+        assert!(matches!(
+            cases([
+                CheckReturn::Unchecked(Unchecked::MissingInput),
+                CheckReturn::Unchecked(Unchecked::NothingToCompare),
+            ])
+            .summarise(),
+            CheckReturn::Unchecked(Unchecked::MissingInput)
+        ));
+    }
+
+    #[test]
+    fn summarise_counts_nested_partial_cases() {
+        let nested = cases([passed(), CheckReturn::Unchecked(Unchecked::MissingInput)]);
+        assert!(matches!(
+            cases([nested, passed()]).summarise(),
+            CheckReturn::Unchecked(Unchecked::Partial {
+                passed: 2,
+                unchecked: 1
+            })
+        ));
+    }
+}
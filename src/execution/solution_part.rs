@@ -1,10 +1,11 @@
-use crate::execution::result::CheckReturn;
+use crate::execution::{letter_art::LetterArt, result::CheckReturn};
 
 #[derive(Debug, Clone)]
 pub enum SolutionPart {
     Unimplemented,
     Number(rust_decimal::Decimal),
     String(String),
+    Letters(LetterArt), //Checked like a string of the recognised letters
 }
 
 #[derive(Debug, Clone)]
@@ -24,14 +25,17 @@ impl SolutionPart {
                 CheckReturn::Unchecked(crate::execution::result::Unchecked::MissingOuput(self))
             }
             (Self::Number(a), Self::Number(b)) => match a.cmp(b) {
-                std::cmp::Ordering::Equal => CheckReturn::Passed,
+                std::cmp::Ordering::Equal => CheckReturn::Passed(self, correct.clone()),
                 unequal => {
                     CheckReturn::Failed(CheckError::Incorrect(self, correct.clone(), Some(unequal)))
                 }
             },
-            (Self::String(a), Self::String(b)) => {
+            (
+                Self::String(a) | Self::Letters(LetterArt { letters: a, .. }),
+                Self::String(b) | Self::Letters(LetterArt { letters: b, .. }),
+            ) => {
                 if a == b {
-                    CheckReturn::Passed
+                    CheckReturn::Passed(self, correct.clone())
                 } else {
                     CheckReturn::Failed(CheckError::Incorrect(self, correct.clone(), None))
                 }
@@ -42,6 +46,22 @@ impl SolutionPart {
     }
 }
 
+impl SolutionPart {
+    /// Interprets an answer written out as text, e.g. in an answer file.
+    pub fn from_answer(answer: &str) -> Self {
+        answer
+            .parse::<rust_decimal::Decimal>()
+            .map_or_else(|_| answer.into(), Self::Number)
+    }
+
+    pub fn agrees_with(&self, other: &Self) -> bool {
+        matches!(
+            self.clone().check_against(other),
+            Some(CheckReturn::Passed(..))
+        )
+    }
+}
+
 /* Unimplemented */
 impl From<()> for SolutionPart {
     fn from(_value: ()) -> Self {
@@ -123,3 +143,10 @@ impl From<bool> for SolutionPart {
         Self::String(value.to_string())
     }
 }
+
+/* Letters */
+impl From<LetterArt> for SolutionPart {
+    fn from(value: LetterArt) -> Self {
+        Self::Letters(value)
+    }
+}
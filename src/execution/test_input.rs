@@ -1,9 +1,110 @@
 use itertools::Itertools;
 
+use crate::{
+    SolutionPart,
+    context::Params,
+    time_key::{TimeDetailDayAndPart, TimeKey},
+};
+
+/// The input a part is tested on, usually converted from a string literal.
+///
+/// Strings are dedented, so the input can be indented along with the code around it:
+/// - A blank first line and a blank last line are dropped, so the input can start and end on lines of its own.
+/// - The indentation common to all non-blank lines is removed, and relative indentation is kept.
+///   A first line that isn't blank follows the opening quote, so it doesn't count towards the common indentation.
+/// - Tabs and spaces each count as one character of indentation, so mixing them gives unexpected results.
+/// - Windows line endings (`\r\n`) become `\n`.
+///
+/// Use [`TestInput::raw`] or [`TestInput::from_file`] for inputs whose leading whitespace is significant.
+///
+/// ```
+/// use aoc_handler::TestInput;
+///
+/// let test_input: TestInput = "
+///     1 2
+///       3
+///     ".into();
+/// ```
 pub enum TestInput {
     None,
     Empty,
-    Input(String),
+    Input(String, Params),
+    Unreadable(std::path::PathBuf), //A file was specified, but couldn't be read
+}
+
+impl TestInput {
+    /// Loads a test input from a file, resolved against the manifest directory of the crate being run.
+    /// The contents are used as they are, without any dedenting.
+    ///
+    /// ```
+    /// use aoc_handler::TestInput;
+    ///
+    /// let test_input = TestInput::from_file("examples/2024/day05_1.txt");
+    /// ```
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Self {
+        let path = resolve(path.as_ref());
+        match std::fs::read_to_string(&path) {
+            Ok(text) if text.trim().is_empty() => Self::Empty,
+            Ok(text) => Self::Input(
+                text.trim_end_matches(['\n', '\r']).to_owned(),
+                Params::new(),
+            ),
+            Err(_) => Self::Unreadable(path),
+        }
+    }
+
+    /// Uses the text exactly as given, without removing any indentation.
+    /// Useful for inputs whose leading whitespace is significant in ways dedenting can't preserve.
+    pub fn raw(text: impl Into<String>) -> Self {
+        let text = text.into();
+        if text.is_empty() {
+            Self::Empty
+        } else {
+            Self::Input(text, Params::new())
+        }
+    }
+
+    /// Looks up a test input and answer by convention, for parts that didn't provide any.
+    /// Inputs are taken from `examples/{year}/day{day}_{part}.txt`, falling back to `examples/{year}/day{day}.txt` for both parts.
+    /// Answers are taken from `examples/{year}/day{day}_{part}.answer`. Days are zero-padded to two digits.
+    pub fn by_convention(key: TimeKey<TimeDetailDayAndPart>) -> Option<(Self, SolutionPart)> {
+        Self::by_convention_in(&resolve(std::path::Path::new("examples")), key)
+    }
+
+    fn by_convention_in(
+        examples: &std::path::Path,
+        key: TimeKey<TimeDetailDayAndPart>,
+    ) -> Option<(Self, SolutionPart)> {
+        let (year, day, part) = key.to_primitive();
+        let directory = examples.join(year.to_string());
+
+        let input = [
+            format!("day{day:02}_{part}.txt"),
+            format!("day{day:02}.txt"),
+        ]
+        .into_iter()
+        .map(|file| directory.join(file))
+        .find(|path| path.exists())
+        .map(Self::from_file)?;
+
+        let answer = std::fs::read_to_string(directory.join(format!("day{day:02}_{part}.answer")))
+            .map_or(SolutionPart::Unimplemented, |answer| {
+                SolutionPart::from_answer(answer.trim())
+            });
+
+        Some((input, answer))
+    }
+
+    /// Declares a parameter for this test input, readable from within the solver through [`Context::param`](crate::Context::param).
+    /// Has no effect on missing or elided test inputs.
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)] //Taking by value so that plain numbers can be passed
+    pub fn with_param(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        if let Self::Input(_, params) = &mut self {
+            params.push((name.into(), value.to_string()));
+        }
+        self
+    }
 }
 
 impl From<()> for TestInput {
@@ -17,7 +118,7 @@ impl From<&str> for TestInput {
         if value.is_empty() {
             Self::Empty
         } else {
-            Self::Input(dedent(value))
+            Self::Input(dedent(value), Params::new())
         }
     }
 }
@@ -27,13 +128,202 @@ impl From<String> for TestInput {
         if value.is_empty() {
             Self::Empty
         } else {
-            Self::Input(dedent(&value))
+            Self::Input(dedent(&value), Params::new())
+        }
+    }
+}
+
+/// Resolves a path against the manifest directory of the crate being run, where examples and day modules live.
+//? Cargo sets the manifest directory for `cargo run` and `cargo test`. Outside of those, the working directory is the best guess
+pub fn resolve(path: &std::path::Path) -> std::path::PathBuf {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map_or_else(std::path::PathBuf::new, std::path::PathBuf::from)
+        .join(path)
+}
+
+/// A named test case, for puzzles that provide more than one example per part.
+///
+/// ```
+/// use aoc_handler::TestCase;
+///
+/// let case = TestCase::new("Larger example", "1\n2\n3\n4", 10);
+/// ```
+pub struct TestCase {
+    pub(crate) name: String,
+    pub(crate) input: TestInput,
+    pub(crate) answer: SolutionPart,
+}
+
+impl TestCase {
+    pub fn new(
+        name: impl Into<String>,
+        input: impl Into<TestInput>,
+        answer: impl Into<SolutionPart>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            input: input.into(),
+            answer: answer.into(),
         }
     }
 }
 
+/// Removes the indentation common to all lines, in the manner of `indoc`.
+/// A blank first or last line is dropped, so the input can start and end on lines of its own.
 fn dedent(input: &str) -> String {
-    //? I'm unaware of any AoC input that actually relies on leading whitespace anywhere
-    //? If something like that arises, I will add proper dedentation a la indoc
-    input.lines().map(str::trim_start).join("\n")
+    let input = input.replace("\r\n", "\n");
+    let mut lines = input.split('\n').collect_vec();
+
+    //? The first line follows the opening quote, so its indentation only counts if it's on a line of its own
+    let first_on_own_line = lines.first().is_some_and(|line| line.trim().is_empty());
+    if first_on_own_line {
+        lines.remove(0);
+    }
+    if lines.len() > 1 && lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let indentation = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let common = lines
+        .iter()
+        .skip(usize::from(!first_on_own_line))
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| indentation(line))
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|line| &line[indentation(line).min(common)..])
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TestInput, dedent};
+    use crate::{
+        Handler, Results, SolutionPart, Solver,
+        result::{CheckReturn, PartOutcome, Unchecked},
+        time_key::{TimeDetailDayAndPart, TimeKey},
+    };
+
+    #[test]
+    fn drops_blank_first_and_last_lines() {
+        assert_eq!(dedent("\n    a\n    b\n    "), "a\nb");
+        assert_eq!(dedent("\n\n    a\n\n"), "\na\n");
+    }
+
+    #[test]
+    fn keeps_relative_indentation() {
+        assert_eq!(dedent("\n    a\n      b\n\n    c\n"), "a\n  b\n\nc");
+    }
+
+    #[test]
+    fn ignores_the_indentation_of_an_unindented_first_line() {
+        assert_eq!(dedent("a\n    b\n      c"), "a\nb\n  c");
+    }
+
+    #[test]
+    fn normalises_crlf() {
+        assert_eq!(dedent("\r\n    a\r\n    b\r\n"), "a\nb");
+    }
+
+    #[test]
+    fn counts_tabs_like_spaces() {
+        assert_eq!(dedent("\n\ta\n\t\tb"), "a\n\tb");
+        assert_eq!(dedent("\n\ta\n  b"), "a\n b");
+    }
+
+    #[test]
+    fn raw_bypasses_dedenting() {
+        let TestInput::Input(text, _) = TestInput::raw("\n    a\n    b") else {
+            panic!("raw input was not kept");
+        };
+        assert_eq!(text, "\n    a\n    b");
+
+        let TestInput::Input(text, _) = TestInput::from("\n    a\n    b") else {
+            panic!("input was not kept");
+        };
+        assert_eq!(text, "a\nb");
+    }
+
+    fn key(part: u8) -> TimeKey<TimeDetailDayAndPart> {
+        TimeKey::<TimeDetailDayAndPart>::new(2015, 1, part).unwrap()
+    }
+
+    fn input_text(test_input: &TestInput) -> &str {
+        match test_input {
+            TestInput::Input(text, _) => text,
+            _ => panic!("Expected an input"),
+        }
+    }
+
+    #[test]
+    fn conventional_inputs_fall_back_to_the_whole_day() {
+        let examples = tempfile::tempdir().unwrap();
+        let directory = examples.path().join("2015");
+        std::fs::create_dir(&directory).unwrap();
+        assert!(TestInput::by_convention_in(examples.path(), key(1)).is_none());
+
+        std::fs::write(directory.join("day01.txt"), "both parts\n").unwrap();
+        std::fs::write(directory.join("day01_1.txt"), "part one\r\n").unwrap();
+
+        let (part_1, _) = TestInput::by_convention_in(examples.path(), key(1)).unwrap();
+        let (part_2, _) = TestInput::by_convention_in(examples.path(), key(2)).unwrap();
+        assert_eq!(input_text(&part_1), "part one");
+        assert_eq!(input_text(&part_2), "both parts");
+    }
+
+    #[test]
+    fn conventional_answers_are_read_like_answer_files() {
+        let examples = tempfile::tempdir().unwrap();
+        let directory = examples.path().join("2015");
+        std::fs::create_dir(&directory).unwrap();
+        std::fs::write(directory.join("day01.txt"), "input").unwrap();
+        std::fs::write(directory.join("day01_1.answer"), "42\n").unwrap();
+        std::fs::write(directory.join("day01_2.answer"), " ABC \n").unwrap();
+
+        let (_, part_1) = TestInput::by_convention_in(examples.path(), key(1)).unwrap();
+        let (_, part_2) = TestInput::by_convention_in(examples.path(), key(2)).unwrap();
+        assert!(matches!(part_1, SolutionPart::Number(n) if n == 42.into()));
+        assert!(matches!(part_2, SolutionPart::String(s) if s == "ABC"));
+
+        std::fs::remove_file(directory.join("day01_1.answer")).unwrap();
+        let (_, part_1) = TestInput::by_convention_in(examples.path(), key(1)).unwrap();
+        assert!(matches!(part_1, SolutionPart::Unimplemented));
+    }
+
+    #[test]
+    fn unreadable_files_leave_the_part_unchecked() {
+        let directory = tempfile::tempdir().unwrap();
+        let empty = directory.path().join("empty.txt");
+        std::fs::write(&empty, "\n").unwrap();
+        assert!(matches!(TestInput::from_file(&empty), TestInput::Empty));
+
+        //? A directory exists, but can't be read as a file
+        for path in [
+            directory.path().join("missing.txt"),
+            directory.path().to_owned(),
+        ] {
+            let mapper = |_year, _day| {
+                Some(
+                    Solver::new(|_: &str| 1, |_: &str| ())
+                        .with_part_1_test(TestInput::from_file(&path), 1),
+                )
+            };
+            let report = Handler::new(&mapper)
+                .with_offline(true)
+                .reports()
+                .check_part(2015, 1, 1)
+                .unwrap();
+            let Results::Part(.., Ok(output)) = report.results() else {
+                panic!("The part should have been checked");
+            };
+            assert!(matches!(
+                &output.outcome,
+                PartOutcome::Checked(CheckReturn::Unchecked(Unchecked::UnreadableInput(unreadable)))
+                    if *unreadable == path
+            ));
+        }
+    }
 }
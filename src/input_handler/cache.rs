@@ -17,7 +17,7 @@ pub fn clear_cached(sub_path: &Path) -> std::io::Result<()> {
     std::fs::remove_file(file_path(sub_path))
 }
 
-fn file_path(sub_path: &Path) -> PathBuf {
+pub fn file_path(sub_path: &Path) -> PathBuf {
     let path = dirs::cache_dir()
         .expect("dirs did not provide a cache location.")
         .join("aoc_handler") //Parent folder for this cache
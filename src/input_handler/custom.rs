@@ -0,0 +1,22 @@
+use super::InputError;
+
+/// An input supplied by the user instead of being fetched from Advent of Code, e.g. a hand-crafted edge case.
+pub enum CustomInput {
+    Text(String),
+    File(std::path::PathBuf),
+    Stdin,
+}
+
+impl CustomInput {
+    pub fn read(self) -> Result<String, InputError> {
+        let input = match self {
+            Self::Text(text) => text,
+            Self::File(path) => std::fs::read_to_string(&path)
+                .map_err(|e| InputError::CustomRead(path, std::rc::Rc::new(e)))?,
+            Self::Stdin => std::io::read_to_string(std::io::stdin())
+                .map_err(|e| InputError::CustomRead("stdin".into(), std::rc::Rc::new(e)))?,
+        };
+        //? Only trailing whitespace, leading whitespace might be deliberate in a hand-crafted input
+        Ok(input.trim_end().to_owned())
+    }
+}
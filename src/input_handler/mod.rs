@@ -4,6 +4,8 @@ use crate::{
 };
 
 mod cache;
+mod custom;
+pub use custom::CustomInput;
 mod token;
 use token::Token;
 
@@ -35,13 +37,12 @@ impl Client {
     /// Preferentially sources from internal cache.
     pub fn get_day_input(&self, key: TimeKey<TimeDetailDay>) -> Result<String, InputError> {
         let (year, day) = key.to_primitive();
-        let sub_path: std::path::PathBuf = format!("{year}/day{day}_input.txt").into();
+        let sub_path = day_input_path(key);
 
         //If the file is already cached, we trust that it's fine, because we wouldn't cache a broken file.
         //(And if we did, the user can delete the cache themselves)
         if cache::is_cached(&sub_path) {
-            //This can only fail if the file is *there*, but couldn't be read to string. That's weird.
-            return cache::text::get_cached(&sub_path).map_err(|_| InputError::CacheRead(sub_path));
+            return get_cached_day_input(key);
         }
 
         let url = format!("https://adventofcode.com/{year}/day/{day}/input");
@@ -73,6 +74,38 @@ impl Client {
         cache::text::cache(&sub_path, &input)?;
         Ok(input)
     }
+
+    /// Fetches the puzzle description page for a given day, as HTML. Never cached, since it grows once part one is solved.
+    pub fn get_puzzle_page(&self, key: TimeKey<TimeDetailDay>) -> Result<String, InputError> {
+        let (year, day) = key.to_primitive();
+        let url = format!("https://adventofcode.com/{year}/day/{day}");
+
+        let response = self
+            .client
+            .get(url)
+            .header("Cookie", format!("session={}", self.token))
+            .send()?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(InputError::NotFound { year, day });
+        }
+        Ok(response.text()?)
+    }
+}
+
+/// Fetches the input data for a given day and year from the cache only, without ever contacting the Advent of Code servers.
+pub fn get_cached_day_input(key: TimeKey<TimeDetailDay>) -> Result<String, InputError> {
+    let (year, day) = key.to_primitive();
+    let sub_path = day_input_path(key);
+    if !cache::is_cached(&sub_path) {
+        return Err(InputError::NotCached { year, day });
+    }
+    //This can only fail if the file is *there*, but couldn't be read to string. That's weird.
+    cache::text::get_cached(&sub_path).map_err(|_| InputError::CacheRead(sub_path))
+}
+
+fn day_input_path(key: TimeKey<TimeDetailDay>) -> std::path::PathBuf {
+    let (year, day) = key.to_primitive();
+    format!("{year}/day{day}_input.txt").into()
 }
 
 #[derive(Debug, thiserror::Error, Clone)]
@@ -87,6 +120,12 @@ pub enum InputError {
     InvalidToken,
     #[error("No input data found for {year}-{day}")]
     NotFound { year: Year, day: Day },
+    #[error("No cached input for {year}-{day}, and fetching is disabled in offline mode")]
+    NotCached { year: Year, day: Day },
+    #[error("Unable to read input from {0}. {1}")]
+    CustomRead(std::path::PathBuf, #[source] std::rc::Rc<std::io::Error>),
+    #[error("Unable to save counterexample to {0}. {1}")]
+    CounterexampleWrite(std::path::PathBuf, #[source] std::rc::Rc<std::io::Error>),
 }
 
 impl From<std::io::Error> for InputError {
@@ -0,0 +1,361 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue, terminal,
+};
+
+use crate::{
+    Day, Handler, HandlerError, Part, Report, Year,
+    api::display::{
+        self,
+        style::{bold, dim, fail, pass, warn},
+    },
+    execution::result::{AcquisitionError, CheckReturn, CheckedRunReturn, PartOutcome, PartReturn},
+    time_key::{self, TimeDetailDay, TimeDetailDayAndPart, TimeDetailNone, TimeKey},
+};
+
+const BENCH_RUNS: usize = 10;
+
+impl Handler<'_> {
+    /// An interactive menu of years and days, to check, run and benchmark them with the keyboard. Requires the `interactive` feature.
+    /// Everything runs in one session, so the input client and session token are reused across actions.
+    pub fn interactive(&self) -> std::io::Result<()> {
+        let mut menu = Menu::new(self);
+        let mut stdout = std::io::stdout();
+
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        let result = menu.run(&mut stdout);
+        //? Restoring the terminal even if the menu failed, the error is reported afterwards
+        execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()?;
+        result
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Screen {
+    Years,
+    Days(Year),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    Check,
+    Run,
+    Bench,
+}
+
+struct Menu<'h> {
+    handler: &'h Handler<'h>,
+    years: Vec<Year>, //Most recent first
+    mapped: BTreeSet<(Year, Day)>,
+    results: BTreeMap<(Year, Day, Part), PartReturn>, //The latest result of every part that was checked or run
+    screen: Screen,
+    year_cursor: usize,
+    day_cursor: usize,
+    output: String,
+}
+
+impl<'h> Menu<'h> {
+    fn new(handler: &'h Handler<'h>) -> Self {
+        let years: Vec<Year> = (2015..=time_key::current_year()).rev().collect();
+        let mapped = years
+            .iter()
+            .filter_map(|&year| TimeKey::<TimeDetailNone>::new(year).ok())
+            .flat_map(TimeKey::iterate)
+            .map(TimeKey::<TimeDetailDay>::to_primitive)
+            .filter(|&(year, day)| (handler.mapper)(year, day).is_some())
+            .collect();
+
+        Self {
+            handler,
+            years,
+            mapped,
+            results: BTreeMap::new(),
+            screen: Screen::Years,
+            year_cursor: 0,
+            day_cursor: 0,
+            output: String::new(),
+        }
+    }
+
+    fn run(&mut self, out: &mut impl Write) -> std::io::Result<()> {
+        loop {
+            self.draw(out)?;
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(());
+                }
+                KeyCode::Char('q') => return Ok(()),
+                KeyCode::Up | KeyCode::Char('k') => self.move_cursor(false),
+                KeyCode::Down | KeyCode::Char('j') => self.move_cursor(true),
+                KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => self.open_year(),
+                KeyCode::Esc | KeyCode::Left | KeyCode::Backspace | KeyCode::Char('h') => {
+                    self.screen = Screen::Years;
+                }
+                KeyCode::Char('c') => self.act(out, Action::Check)?,
+                KeyCode::Char('r') => self.act(out, Action::Run)?,
+                KeyCode::Char('b') => self.act(out, Action::Bench)?,
+                _ => (),
+            }
+        }
+    }
+
+    fn move_cursor(&mut self, down: bool) {
+        let (cursor, len) = match self.screen {
+            Screen::Years => (&mut self.year_cursor, self.years.len()),
+            Screen::Days(year) => (&mut self.day_cursor, day_count(year)),
+        };
+        *cursor = if down {
+            (*cursor + 1).min(len.saturating_sub(1))
+        } else {
+            cursor.saturating_sub(1)
+        };
+    }
+
+    /// Opens the selected year, if there is one.
+    fn open_year(&mut self) {
+        if matches!(self.screen, Screen::Years)
+            && let Some(&year) = self.years.get(self.year_cursor)
+        {
+            self.screen = Screen::Days(year);
+            self.day_cursor = 0;
+        }
+    }
+
+    fn act(&mut self, out: &mut impl Write, action: Action) -> std::io::Result<()> {
+        let selected = match self.screen {
+            Screen::Years => self.years.get(self.year_cursor).copied(),
+            Screen::Days(year) => Some(year),
+        };
+        let Some(year) = selected else {
+            return Ok(());
+        };
+        self.output = dim(match action {
+            Action::Check => "Checking…",
+            Action::Run => "Running…",
+            Action::Bench => "Benchmarking…",
+        });
+        self.draw(out)?;
+
+        let reports = self.handler.reports();
+        self.output = match (self.screen, action) {
+            (Screen::Years, Action::Check) => self.show(reports.check_year(year)),
+            (Screen::Years, Action::Run) => self.show(reports.run_year(year)),
+            (Screen::Years, Action::Bench) => {
+                "Benchmarks are run per day, open a year first".to_owned()
+            }
+            (Screen::Days(_), Action::Check) => self.show(reports.check_day(year, self.day())),
+            (Screen::Days(_), Action::Run) => self.show(reports.run_day(year, self.day())),
+            (Screen::Days(_), Action::Bench) => self.bench(year, self.day()),
+        };
+        Ok(())
+    }
+
+    fn day(&self) -> Day {
+        Day::try_from(self.day_cursor + 1).expect("Internal error: Day cursor out of range.")
+    }
+
+    /// Remembers the results of a report for the status column, and renders it.
+    fn show(&mut self, report: Result<Report, HandlerError>) -> String {
+        match report {
+            Ok(report) => {
+                for (year, day, part, part_return) in report.parts() {
+                    self.results.insert((year, day, part), part_return.clone());
+                }
+                report.render()
+            }
+            Err(he) => he.to_string(),
+        }
+    }
+
+    /// Runs both parts repeatedly on the real input. A run that doesn't produce a time, e.g. because its test failed, is shown instead.
+    /// Only the first run checks the tests, the others only run on the real input, and have to agree with the first.
+    fn bench(&mut self, year: Year, day: Day) -> String {
+        let reports = self.handler.reports();
+        let run_return = |report: &Result<Report, HandlerError>| {
+            let (.., part_return) = report.as_ref().ok()?.parts().next()?;
+            part_return.as_ref().ok()?.outcome.run_return().cloned()
+        };
+
+        let mut rendered = Vec::new();
+        for part in 1..=2 {
+            let first = reports.run_part(year, day, part);
+            let Some(first_return) = run_return(&first) else {
+                rendered.push(self.show(first));
+                continue;
+            };
+            let input = match TimeKey::<TimeDetailDay>::new(year, day)
+                .map_err(HandlerError::from)
+                .and_then(|key| Ok(self.handler.day_input(key)?))
+            {
+                Ok(input) => input,
+                Err(he) => {
+                    rendered.push(he.to_string());
+                    continue;
+                }
+            };
+
+            let mut times = Vec::with_capacity(BENCH_RUNS);
+            times.push(first_return.time_taken);
+            for _ in 1..BENCH_RUNS {
+                let report = reports.run_part_on_input(
+                    year,
+                    day,
+                    part,
+                    &input,
+                    first_return.solution_part.clone(),
+                );
+                let Some(run_return) = run_return(&report) else {
+                    rendered.push(self.show(report));
+                    break;
+                };
+                times.push(run_return.time_taken);
+            }
+            if times.len() == BENCH_RUNS {
+                let time_key = TimeKey::<TimeDetailDayAndPart>::new(year, day, part)
+                    .expect("Internal error: Benchmarked part was invalid.");
+                rendered.push(display::render_bench(time_key, times));
+            }
+        }
+        rendered.join("\n")
+    }
+
+    fn draw(&self, out: &mut impl Write) -> std::io::Result<()> {
+        let mut lines = vec![
+            bold("Advent of Code"),
+            dim("↑↓ select  ⏎ open  esc back  c check  r run  b bench  q quit"),
+            String::new(),
+        ];
+
+        match self.screen {
+            Screen::Years => {
+                for (index, &year) in self.years.iter().enumerate() {
+                    let mapped = self.mapped.iter().filter(|&&(y, _)| y == year).count();
+                    lines.push(format!(
+                        "{} {year}  {}",
+                        cursor_mark(index == self.year_cursor),
+                        dim(format!("{mapped:>2}/{} days mapped", day_count(year)))
+                    ));
+                }
+            }
+            Screen::Days(year) => {
+                lines.push(bold(year));
+                for day in 1..=day_count(year) {
+                    let day = Day::try_from(day).expect("Internal error: Day out of range.");
+                    let status = if self.mapped.contains(&(year, day)) {
+                        format!(
+                            "{} {}",
+                            self.part_status(year, day, 1),
+                            self.part_status(year, day, 2)
+                        )
+                    } else {
+                        dim("not mapped")
+                    };
+                    lines.push(format!(
+                        "{} {day:>2}  {status}",
+                        cursor_mark(usize::from(day) == self.day_cursor + 1)
+                    ));
+                }
+            }
+        }
+
+        if !self.output.is_empty() {
+            lines.push(String::new());
+            lines.extend(self.output.lines().map(str::to_owned));
+        }
+
+        queue!(
+            out,
+            cursor::MoveTo(0, 0),
+            terminal::Clear(terminal::ClearType::All)
+        )?;
+        //? Raw mode doesn't return the carriage on a new line
+        write!(out, "{}", lines.join("\r\n"))?;
+        out.flush()
+    }
+
+    /// A single glyph for the latest result of a part.
+    fn part_status(&self, year: Year, day: Day, part: Part) -> String {
+        match self.results.get(&(year, day, part)) {
+            None => dim("-"),
+            Some(Err(AcquisitionError::NotMapped)) => dim("·"),
+            Some(Err(AcquisitionError::Unimplemented)) => dim("○"),
+            Some(Ok(output)) if output.outcome.failed() => fail("✗"),
+            Some(Ok(output)) => match &output.outcome {
+                PartOutcome::Checked(CheckReturn::Passed(..))
+                | PartOutcome::CheckedAndRan(CheckedRunReturn::Ok { .. }) => pass("✓"),
+                _ => warn("?"), //Nothing failed, but nothing was verified either
+            },
+        }
+    }
+}
+
+fn day_count(year: Year) -> usize {
+    TimeKey::<TimeDetailNone>::new(year).map_or(0, |time_key| time_key.iterate().count())
+}
+
+fn cursor_mark(selected: bool) -> String {
+    if selected {
+        bold("›")
+    } else {
+        " ".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, Menu, Screen};
+    use crate::{Day, Handler, Solver, Year};
+
+    #[test]
+    fn cursor_stays_in_range() {
+        let mapper = |_year: Year, _day: Day| -> Option<Solver> { None };
+        let handler = Handler::new(&mapper);
+        let mut menu = Menu::new(&handler);
+
+        menu.screen = Screen::Days(2015);
+        for _ in 0..30 {
+            menu.move_cursor(true);
+        }
+        assert_eq!(menu.day_cursor, 24);
+        menu.move_cursor(false);
+        assert_eq!(menu.day_cursor, 23);
+
+        menu.screen = Screen::Years;
+        menu.years.clear();
+        menu.move_cursor(true);
+        menu.move_cursor(false);
+        assert_eq!(menu.year_cursor, 0);
+    }
+
+    #[test]
+    fn nothing_happens_without_years() {
+        let mapper = |_year: Year, _day: Day| -> Option<Solver> { None };
+        let handler = Handler::new(&mapper);
+        let mut menu = Menu::new(&handler);
+        menu.years.clear();
+
+        menu.open_year();
+        assert!(matches!(menu.screen, Screen::Years));
+
+        let mut out = Vec::new();
+        for action in [Action::Check, Action::Run, Action::Bench] {
+            menu.act(&mut out, action).unwrap();
+        }
+        assert!(out.is_empty());
+        assert!(menu.output.is_empty());
+    }
+}
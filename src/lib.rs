@@ -10,19 +10,60 @@
 #![feature(never_type, bool_to_result)]
 
 mod api;
-pub use api::traits::{DaySolver, WrapSolver};
+pub use api::display::{Colour, Theme};
+pub use api::traits::{
+    CombinedDaySolver, DaySolver, ParsedDaySolver, WrapCombinedSolver, WrapParsedSolver, WrapSolver,
+};
+pub use api::{
+    ExitPolicy, Failure, Markdown, OutputFormat, Report, Reports, Results, SectionError,
+};
 
 mod time_key;
-pub use time_key::{Day, Part, Year};
+pub use time_key::{Day, Part, TimeKeyError, Year};
 
 mod execution;
-pub use execution::{SolutionPart, TestInput};
+pub use execution::{LetterArt, Request, SolutionPart, TestCase, TestInput, result};
 
 mod input_handler;
+pub use input_handler::InputError;
+
+mod solver;
+pub use solver::{Implementation, Solver};
+
+mod context;
+pub use context::Context;
+
+mod error;
+pub use error::HandlerError;
+
+mod mapper;
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::mapper::{checked_day, checked_key};
+    #[cfg(feature = "registry")]
+    pub use {crate::registry::Registration, inventory};
+}
+
+#[cfg(feature = "registry")]
+mod registry;
+#[cfg(feature = "registry")]
+pub use {aoc_handler_macros::day, registry::RegistryError};
+
+mod scaffold;
+pub use scaffold::{Scaffold, ScaffoldError, ScaffoldStep};
+
+#[cfg(feature = "cli")]
+mod cli;
+
+#[cfg(feature = "interactive")]
+mod interactive;
 
 pub struct Handler<'a> {
     input: std::cell::LazyCell<input_handler::Client>,
     mapper: &'a dyn Fn(Year, Day) -> Option<Solver>,
+    offline: bool,
+    format: OutputFormat,
+    counterexample_dir: Option<std::path::PathBuf>,
 }
 
 impl<'a> Handler<'a> {
@@ -30,17 +71,31 @@ impl<'a> Handler<'a> {
         Self {
             input: std::cell::LazyCell::new(input_handler::Client::new),
             mapper,
+            offline: false,
+            format: OutputFormat::Text,
+            counterexample_dir: None,
         }
     }
-}
 
-pub struct Solver {
-    part_1: SolverPart,
-    part_2: SolverPart,
-}
+    /// In offline mode, only inputs that are already cached are used, and nothing is fetched from the Advent of Code servers.
+    #[must_use]
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
 
-struct SolverPart {
-    solver: &'static dyn Fn(&str) -> execution::SolutionPart,
-    test_input: TestInput,
-    test_answer: execution::SolutionPart,
+    /// How the printing `check_*` and `run_*` methods show their results. Text by default.
+    #[must_use]
+    pub fn with_output_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Where [`Handler::fuzz_part`] saves the inputs implementations disagree on, as `{year}/day{day}_{part}.counterexample.txt`.
+    /// By default, this is the `examples` directory next to the solvers, where test inputs are picked up by convention.
+    #[must_use]
+    pub fn with_counterexample_dir(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.counterexample_dir = Some(dir.into());
+        self
+    }
 }
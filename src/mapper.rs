@@ -0,0 +1,92 @@
+use crate::{
+    Day, Year,
+    time_key::{TimeDetailNone, TimeKey},
+};
+
+/// Builds the mapper [`Handler::new`](crate::Handler::new) expects, from the day modules of each year.
+///
+/// Each module provides a `DayImpl` implementing one of the solver traits. The day is taken from the digits the module name ends in,
+/// so `day01`, `day_1` and `day1` are all day 1. Modules can be given as paths, e.g. `y2023::day01`.
+/// Invalid years, and days that don't exist in their year, are compile errors rather than "No solution provided".
+///
+/// ```
+/// # pub mod day01 { use aoc_handler::{DaySolver, SolutionPart}; pub struct DayImpl;
+/// #     impl DaySolver for DayImpl { fn part_1(_: &str) -> impl Into<SolutionPart> {} fn part_2(_: &str) -> impl Into<SolutionPart> {} } }
+/// # mod y2024 { pub use super::day01; pub use super::day01 as day_12; }
+/// use aoc_handler::Handler;
+///
+/// # fn main() {
+/// let mapper = aoc_handler::solvers! {
+///     2023 => [day01],
+///     2024 => [y2024::day01, y2024::day_12],
+/// };
+/// let handler = Handler::new(&mapper);
+/// # assert!(mapper(2024, 12).is_some() && mapper(2024, 2).is_none() && mapper(2023, 1).is_some());
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # mod day13 { use aoc_handler::{DaySolver, SolutionPart}; pub struct DayImpl;
+/// #     impl DaySolver for DayImpl { fn part_1(_: &str) -> impl Into<SolutionPart> {} fn part_2(_: &str) -> impl Into<SolutionPart> {} } }
+/// // 2025 only has 12 days
+/// let mapper = aoc_handler::solvers! { 2025 => [day13] };
+/// ```
+#[macro_export]
+macro_rules! solvers {
+    ($($year:literal => [$($module:ident $(:: $segment:ident)*),* $(,)?]),* $(,)?) => {
+        |year: $crate::Year, day: $crate::Day| -> ::core::option::Option<$crate::Solver> {
+            #[allow(unused_imports)]
+            use $crate::{WrapCombinedSolver as _, WrapParsedSolver as _, WrapSolver as _};
+            $(
+                if year == $year {
+                    $({
+                        const DAY: $crate::Day = $crate::__private::checked_day($year, ::core::stringify!($module $(:: $segment)*));
+                        if day == DAY {
+                            return $module $(:: $segment)*::DayImpl::wrap();
+                        }
+                    })*
+                }
+            )*
+            ::core::option::Option::None
+        }
+    };
+}
+
+/// The day a module path stands for, going by the digits it ends in, validated against its year. Evaluated at compile time by [`solvers!`].
+pub const fn checked_day(year: Year, module_path: &str) -> Day {
+    //? Parsing by hand, since nothing on str is const yet
+    let bytes = module_path.as_bytes();
+    let mut start = bytes.len();
+    while start > 0 && bytes[start - 1].is_ascii_digit() {
+        start -= 1;
+    }
+    assert!(
+        start < bytes.len(),
+        "Invalid module name: Day modules need to end in their day number, e.g. day01"
+    );
+
+    let mut day: u32 = 0;
+    let mut index = start;
+    while index < bytes.len() {
+        day = day * 10 + (bytes[index] - b'0') as u32;
+        assert!(day <= Day::MAX as u32, "Invalid Day: Far too high");
+        index += 1;
+    }
+    #[allow(clippy::cast_possible_truncation)] //Checked right above
+    let day = day as Day;
+    checked_key(year, day).1
+}
+
+/// Validates a year and day at compile time, for the macros registering solvers.
+pub const fn checked_key(year: Year, day: Day) -> (Year, Day) {
+    assert!(year >= 2015, "Invalid Year: Advent of Code started in 2015");
+    assert!(
+        day != 0,
+        "Invalid Day: Days are one-indexed, the first of december is day number 1."
+    );
+    assert!(
+        day <= TimeKey::<TimeDetailNone>::max_days(year),
+        "Invalid Day: Too high for the year, which only has 25 days up to 2024, and 12 since 2025."
+    );
+    (year, day)
+}
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use crate::{Day, Handler, Solver, Year};
+
+/// A solver registered through the [`day`](crate::day) attribute. Collected at link time, and looked up by [`Handler::from_registry`].
+#[doc(hidden)]
+pub struct Registration {
+    year: Year,
+    day: Day,
+    solver: fn() -> Option<Solver>,
+    name: &'static str,
+}
+
+impl Registration {
+    pub const fn new(key: (Year, Day), solver: fn() -> Option<Solver>, name: &'static str) -> Self {
+        let (year, day) = key;
+        Self {
+            year,
+            day,
+            solver,
+            name,
+        }
+    }
+}
+
+inventory::collect!(Registration);
+
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryError {
+    #[error("{year}-{day} is registered more than once, by {}", names.join(", "))]
+    Duplicate {
+        year: Year,
+        day: Day,
+        names: Vec<&'static str>,
+    },
+}
+
+impl Handler<'static> {
+    /// A handler for every solver registered through the [`day`](crate::day) attribute, anywhere in the program, without a central mapper.
+    /// Requires the `registry` feature.
+    ///
+    /// ```no_run
+    /// use aoc_handler::{DaySolver, Handler, RegistryError, SolutionPart};
+    ///
+    /// struct DayImpl;
+    ///
+    /// #[aoc_handler::day(2024, 5)]
+    /// impl DaySolver for DayImpl {
+    ///     fn part_1(input: &str) -> impl Into<SolutionPart> {
+    ///         input.lines().count()
+    ///     }
+    ///
+    ///     fn part_2(_input: &str) -> impl Into<SolutionPart> {}
+    /// }
+    ///
+    /// fn main() -> Result<(), RegistryError> {
+    ///     Handler::from_registry()?.run_day(2024, 5);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_registry() -> Result<Self, RegistryError> {
+        let mut registered: BTreeMap<(Year, Day), Vec<&'static str>> = BTreeMap::new();
+        for registration in inventory::iter::<Registration> {
+            registered
+                .entry((registration.year, registration.day))
+                .or_default()
+                .push(registration.name);
+        }
+
+        //? Reporting the first duplicate, with every place it was registered
+        if let Some(((year, day), names)) =
+            registered.into_iter().find(|(_, names)| names.len() > 1)
+        {
+            return Err(RegistryError::Duplicate { year, day, names });
+        }
+
+        Ok(Self::new(&registered_solver))
+    }
+}
+
+fn registered_solver(year: Year, day: Day) -> Option<Solver> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .find(|registration| registration.year == year && registration.day == day)
+        .and_then(|registration| (registration.solver)())
+}
@@ -0,0 +1,530 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    Day, Handler, InputError, TimeKeyError, Year,
+    execution::resolve,
+    time_key::{TimeDetailDay, TimeKey},
+};
+
+/// Where [`Handler::scaffold_day_with`] puts new days. Paths are relative to the crate being built, unless absolute.
+///
+/// By default, days go into `src/y{year}_day{day}.rs` (days zero-padded), are registered in `src/main.rs`,
+/// and their examples are saved to `examples/{year}/`, where they are picked up by convention (see [`TestInput::by_convention`](crate::TestInput::by_convention)).
+#[derive(Debug, Clone)]
+pub struct Scaffold {
+    module_dir: PathBuf,
+    mapper_file: PathBuf,
+    examples_dir: PathBuf,
+}
+
+impl Default for Scaffold {
+    fn default() -> Self {
+        Self {
+            module_dir: "src".into(),
+            mapper_file: "src/main.rs".into(),
+            examples_dir: "examples".into(),
+        }
+    }
+}
+
+impl Scaffold {
+    /// The directory the `y{year}_day{day}.rs` modules are created in.
+    #[must_use]
+    pub fn with_module_dir(mut self, module_dir: impl Into<PathBuf>) -> Self {
+        self.module_dir = module_dir.into();
+        self
+    }
+
+    /// The file holding the `mod` declarations and the mapper's `match`.
+    #[must_use]
+    pub fn with_mapper_file(mut self, mapper_file: impl Into<PathBuf>) -> Self {
+        self.mapper_file = mapper_file.into();
+        self
+    }
+
+    /// The directory the examples are saved to, one subdirectory per year.
+    #[must_use]
+    pub fn with_examples_dir(mut self, examples_dir: impl Into<PathBuf>) -> Self {
+        self.examples_dir = examples_dir.into();
+        self
+    }
+}
+
+/// What scaffolding a day did, one entry per file or resource touched.
+#[derive(Debug)]
+pub enum ScaffoldStep {
+    Created(PathBuf),
+    AlreadyExists(PathBuf),
+    Registered(PathBuf),
+    AlreadyRegistered(PathBuf),
+    NotRegistered(PathBuf, &'static str),
+    InputCached,
+    InputUnavailable(InputError),
+    ExampleUnavailable(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScaffoldError {
+    #[error(transparent)]
+    TimeKey(#[from] TimeKeyError),
+    #[error("Unable to write {0}. {1}")]
+    Write(PathBuf, #[source] std::io::Error),
+}
+
+impl Handler<'_> {
+    /// Sets up a new day with the default [`Scaffold`] layout, and prints what was done.
+    pub fn scaffold_day(&self, year: Year, day: Day) {
+        match self.scaffold_day_with(year, day, &Scaffold::default()) {
+            Ok(steps) => {
+                for step in steps {
+                    println!("{step}");
+                }
+            }
+            Err(se) => eprintln!("{se}"),
+        }
+    }
+
+    /// Sets up a new day: Creates its module from a template, registers it in the mapper, prefetches the input,
+    /// and saves the first example and its answer from the puzzle page.
+    ///
+    /// Safe to run repeatedly, existing files are never overwritten and existing registrations are left alone.
+    /// Steps that depend on the Advent of Code servers are skipped in offline mode, and their failures are reported rather than returned,
+    /// so that a day can be scaffolded before it unlocks.
+    pub fn scaffold_day_with(
+        &self,
+        year: Year,
+        day: Day,
+        scaffold: &Scaffold,
+    ) -> Result<Vec<ScaffoldStep>, ScaffoldError> {
+        let key = TimeKey::<TimeDetailDay>::new(year, day)?;
+        let mut steps = Vec::new();
+
+        let module = resolve(&scaffold.module_dir).join(format!("{}.rs", module_name(year, day)));
+        steps.push(create_new(&module, &module_template(year, day))?);
+        steps.extend(register(&resolve(&scaffold.mapper_file), year, day)?);
+
+        steps.push(
+            self.day_input(key)
+                .map_or_else(ScaffoldStep::InputUnavailable, |_| {
+                    ScaffoldStep::InputCached
+                }),
+        );
+
+        let examples = resolve(&scaffold.examples_dir).join(year.to_string());
+        let example_path = examples.join(format!("day{day:02}.txt"));
+        let answer_path = examples.join(format!("day{day:02}_1.answer"));
+        if example_path.exists() {
+            steps.push(ScaffoldStep::AlreadyExists(example_path));
+        } else if self.offline {
+            steps.push(ScaffoldStep::ExampleUnavailable(
+                "The puzzle page isn't fetched in offline mode".to_owned(),
+            ));
+        } else {
+            match self.input.get_puzzle_page(key) {
+                Err(ie) => steps.push(ScaffoldStep::ExampleUnavailable(ie.to_string())),
+                Ok(page) => match extract_example(&page) {
+                    None => steps.push(ScaffoldStep::ExampleUnavailable(
+                        "No example found on the puzzle page".to_owned(),
+                    )),
+                    Some((example, answer)) => {
+                        std::fs::create_dir_all(&examples)
+                            .map_err(|e| ScaffoldError::Write(examples.clone(), e))?;
+                        steps.push(create_new(&example_path, &example)?);
+                        if let Some(answer) = answer {
+                            steps.push(create_new(&answer_path, &answer)?);
+                        }
+                    }
+                },
+            }
+        }
+
+        Ok(steps)
+    }
+}
+
+impl std::fmt::Display for ScaffoldStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Created(path) => write!(f, "Created {}", path.display()),
+            Self::AlreadyExists(path) => write!(f, "Kept existing {}", path.display()),
+            Self::Registered(path) => write!(f, "Registered in {}", path.display()),
+            Self::AlreadyRegistered(path) => write!(f, "Already registered in {}", path.display()),
+            Self::NotRegistered(path, reason) => {
+                write!(f, "Not registered in {}: {reason}", path.display())
+            }
+            Self::InputCached => write!(f, "Input cached"),
+            Self::InputUnavailable(ie) => write!(f, "Input not cached: {ie}"),
+            Self::ExampleUnavailable(reason) => write!(f, "Example not saved: {reason}"),
+        }
+    }
+}
+
+/// Writes the file only if it doesn't exist yet. Checked and created in one go, so nothing is ever overwritten.
+fn create_new(path: &Path, contents: &str) -> Result<ScaffoldStep, ScaffoldError> {
+    use std::io::Write;
+
+    match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+    {
+        Ok(mut file) => {
+            file.write_all(contents.as_bytes())
+                .map_err(|e| ScaffoldError::Write(path.to_owned(), e))?;
+            Ok(ScaffoldStep::Created(path.to_owned()))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+            Ok(ScaffoldStep::AlreadyExists(path.to_owned()))
+        }
+        Err(e) => Err(ScaffoldError::Write(path.to_owned(), e)),
+    }
+}
+
+fn module_template(year: Year, day: Day) -> String {
+    format!(
+        "use aoc_handler::{{DaySolver, SolutionPart}};
+
+pub struct DayImpl;
+
+//Tests are read from examples/{year}/day{day:02}.txt and examples/{year}/day{day:02}_{{part}}.answer,
+//unless part_1_test_input and part_1_test_answer (and their part 2 counterparts) are provided here.
+impl DaySolver for DayImpl {{
+    fn part_1(_input: &str) -> impl Into<SolutionPart> {{}}
+
+    fn part_2(_input: &str) -> impl Into<SolutionPart> {{}}
+}}
+"
+    )
+}
+
+/// The module of a day, qualified by its year so that several years can share a crate, e.g. `y2024_day06`.
+fn module_name(year: Year, day: Day) -> String {
+    format!("y{year}_day{day:02}")
+}
+
+/// The year and day a module name made by [`module_name`] stands for.
+fn parse_module_name(module: &str) -> Option<(Year, Day)> {
+    let (year, day) = module.strip_prefix('y')?.split_once("_day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Adds the day to the mapper file, see [`register_in`].
+fn register(mapper_file: &Path, year: Year, day: Day) -> Result<Vec<ScaffoldStep>, ScaffoldError> {
+    let Ok(source) = std::fs::read_to_string(mapper_file) else {
+        return Ok(vec![ScaffoldStep::NotRegistered(
+            mapper_file.to_owned(),
+            "The file couldn't be read",
+        )]);
+    };
+    let (registered, not_registered) = register_in(&source, year, day);
+
+    let mut steps = Vec::new();
+    if let Some(reason) = not_registered {
+        steps.push(ScaffoldStep::NotRegistered(mapper_file.to_owned(), reason));
+    }
+    if let Some(registered) = registered {
+        std::fs::write(mapper_file, registered)
+            .map_err(|e| ScaffoldError::Write(mapper_file.to_owned(), e))?;
+        steps.push(ScaffoldStep::Registered(mapper_file.to_owned()));
+    } else if steps.is_empty() {
+        steps.push(ScaffoldStep::AlreadyRegistered(mapper_file.to_owned()));
+    }
+    Ok(steps)
+}
+
+/// Adds the `mod` declaration, the `WrapSolver` import and the match arm for the day to the source of a mapper file,
+/// keeping declarations and arms ordered by year and day.
+/// The arm is inserted into the `match (year, day)` that ends in `_ => None,`, as laid out in the README.
+///
+/// Returns the new source if anything changed, and why the arm couldn't be added if it couldn't.
+fn register_in(source: &str, year: Year, day: Day) -> (Option<String>, Option<&'static str>) {
+    let mut lines: Vec<String> = source.lines().map(str::to_owned).collect();
+    let mut changed = false;
+    let mut not_registered = None;
+
+    let module = module_name(year, day);
+    if !lines
+        .iter()
+        .any(|line| line.trim() == format!("mod {module};"))
+    {
+        let at = ordered_position(&lines, (year, day), |line| {
+            parse_module_name(line.strip_prefix("mod ")?.strip_suffix(';')?)
+        })
+        .unwrap_or_else(|| after_declarations(&lines, &["use ", "mod "]));
+        lines.insert(at, format!("mod {module};"));
+        changed = true;
+    }
+
+    let arm_pattern = format!("({year}, {day}) =>");
+    let arm_registered = lines.iter().any(|line| {
+        line.contains(&format!("{module}::")) || line.trim_start().starts_with(&arm_pattern)
+    });
+    let fallback = lines.iter().position(|line| line.trim() == "_ => None,");
+    match (arm_registered, fallback) {
+        (true, _) => (),
+        (false, Some(fallback)) => {
+            let at = ordered_position(&lines, (year, day), |line| {
+                let (year, day) = line
+                    .strip_prefix('(')?
+                    .split_once(") =>")?
+                    .0
+                    .split_once(',')?;
+                Some((year.trim().parse().ok()?, day.trim().parse().ok()?))
+            })
+            .unwrap_or(fallback);
+            let indent =
+                &lines[fallback][..lines[fallback].len() - lines[fallback].trim_start().len()];
+            lines.insert(
+                at,
+                format!("{indent}{arm_pattern} {module}::DayImpl::wrap(),"),
+            );
+            changed = true;
+
+            //? The arm calls the trait method, which needs the trait in scope
+            if !lines.iter().any(|line| line.contains("WrapSolver")) {
+                let at = after_declarations(&lines, &["use "]);
+                lines.insert(at, "use aoc_handler::WrapSolver;".to_owned());
+            }
+        }
+        (false, None) => not_registered = Some("No `_ => None,` arm to add the day before"),
+    }
+
+    let registered = changed.then(|| {
+        let mut source = lines.join("\n");
+        source.push('\n');
+        source
+    });
+    (registered, not_registered)
+}
+
+/// Where a line for the day goes among the existing lines for other days, which `day_of` recognises.
+/// `None` if there are no such lines.
+fn ordered_position(
+    lines: &[String],
+    day: (Year, Day),
+    day_of: impl Fn(&str) -> Option<(Year, Day)>,
+) -> Option<usize> {
+    let existing: Vec<(usize, (Year, Day))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| day_of(line.trim()).map(|other| (index, other)))
+        .collect();
+    let (last, _) = existing.last()?;
+
+    Some(
+        existing
+            .iter()
+            .find(|&&(_, other)| other > day)
+            .map_or(last + 1, |&(index, _)| index),
+    )
+}
+
+/// The line after the last top level declaration starting with one of the `keywords`, or the start of the file if there are none.
+fn after_declarations(lines: &[String], keywords: &[&str]) -> usize {
+    let Some(start) = lines
+        .iter()
+        .rposition(|line| keywords.iter().any(|keyword| line.starts_with(keyword)))
+    else {
+        return 0;
+    };
+    //? Declarations can span several lines, e.g. grouped imports
+    lines[start..]
+        .iter()
+        .position(|line| line.trim_end().ends_with(';'))
+        .map_or(start + 1, |offset| start + offset + 1)
+}
+
+/// Finds the first example of the puzzle page and the answer part one gives for it.
+/// The example is the first code block following a mention of "example", the answer is the last emphasised code in part one's description.
+/// Both are heuristics, which hold for most days.
+fn extract_example(page: &str) -> Option<(String, Option<String>)> {
+    let start = page.find("<article")?;
+    let end = page[start..]
+        .find("</article>")
+        .map_or(page.len(), |end| start + end);
+    let article = &page[start..end];
+
+    let search_from = article.to_lowercase().find("example").unwrap_or(0);
+    let block_start =
+        search_from + article[search_from..].find("<pre><code>")? + "<pre><code>".len();
+    let block_end = block_start + article[block_start..].find("</code></pre>")?;
+    let example = decode_html(&article[block_start..block_end])
+        .trim_end_matches('\n')
+        .to_owned();
+
+    let answer = article.rfind("<code><em>").and_then(|answer_start| {
+        let answer_start = answer_start + "<code><em>".len();
+        let answer_end = answer_start + article[answer_start..].find("</em></code>")?;
+        Some(decode_html(&article[answer_start..answer_end]))
+    });
+
+    (!example.is_empty()).then_some((example, answer))
+}
+
+/// Drops tags and decodes the entities Advent of Code uses.
+fn decode_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    //? &amp; goes last, so that escaped entities aren't decoded twice
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_example, register_in};
+
+    const MAPPER: &str = "use aoc_handler::{Day, Handler, Solver, WrapSolver, Year};
+
+mod y2023_day25;
+mod y2024_day01;
+mod y2024_day10;
+
+fn map(year: Year, day: Day) -> Option<Solver> {
+    match (year, day) {
+        (2023, 25) => y2023_day25::DayImpl::wrap(),
+        (2024, 1) => y2024_day01::DayImpl::wrap(),
+        (2024, 10) => y2024_day10::DayImpl::wrap(),
+        _ => None,
+    }
+}
+";
+
+    #[test]
+    fn registers_in_year_and_day_order() {
+        let (registered, not_registered) = register_in(MAPPER, 2024, 6);
+        let registered = registered.unwrap();
+        assert_eq!(not_registered, None);
+        assert!(registered.contains("mod y2024_day01;\nmod y2024_day06;\nmod y2024_day10;\n"));
+        assert!(registered.contains(
+            "        (2024, 1) => y2024_day01::DayImpl::wrap(),
+        (2024, 6) => y2024_day06::DayImpl::wrap(),
+        (2024, 10) => y2024_day10::DayImpl::wrap(),"
+        ));
+    }
+
+    #[test]
+    fn keeps_years_apart() {
+        //? The same day of an earlier year is a different day
+        let registered = register_in(MAPPER, 2023, 1).0.unwrap();
+        assert!(registered.contains("mod y2023_day01;\nmod y2023_day25;\n"));
+        assert!(registered.contains(
+            "    match (year, day) {
+        (2023, 1) => y2023_day01::DayImpl::wrap(),
+        (2023, 25) =>"
+        ));
+    }
+
+    #[test]
+    fn is_idempotent() {
+        let registered = register_in(MAPPER, 2024, 6).0.unwrap();
+        assert_eq!(register_in(&registered, 2024, 6), (None, None));
+        assert_eq!(register_in(MAPPER, 2024, 10), (None, None));
+    }
+
+    #[test]
+    fn recognises_arms_written_by_hand() {
+        let mapper = MAPPER
+            .replace("mod y2024_day10;\n", "mod custom;\n")
+            .replace(
+                "(2024, 10) => y2024_day10::DayImpl::wrap(),",
+                "(2024, 10) => custom::solver(),",
+            );
+        let registered = register_in(&mapper, 2024, 10).0.unwrap();
+        assert!(!registered.contains("y2024_day10::"));
+    }
+
+    #[test]
+    fn imports_wrap_solver_for_the_arm() {
+        let mapper = "use aoc_handler::{Day, Solver, Year};
+
+fn map(year: Year, day: Day) -> Option<Solver> {
+    match (year, day) {
+        _ => None,
+    }
+}
+";
+        let registered = register_in(mapper, 2024, 6).0.unwrap();
+        assert_eq!(
+            registered,
+            "use aoc_handler::{Day, Solver, Year};
+use aoc_handler::WrapSolver;
+mod y2024_day06;
+
+fn map(year: Year, day: Day) -> Option<Solver> {
+    match (year, day) {
+        (2024, 6) => y2024_day06::DayImpl::wrap(),
+        _ => None,
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn reports_a_missing_fallback_arm() {
+        let (registered, not_registered) = register_in("fn main() {}\n", 2024, 6);
+        assert_eq!(
+            registered.as_deref(),
+            Some("mod y2024_day06;\nfn main() {}\n")
+        );
+        assert!(not_registered.is_some());
+    }
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 6: Test ---</h2>
+<p>Some <code>intro</code> text.</p>
+<p>For example:</p>
+<pre><code>1 &lt; 2
+<em>3</em> &amp;&amp; 4
+</code></pre>
+<p>In this example, the answer is <code><em>42</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn extracts_the_first_example_and_answer() {
+        assert_eq!(
+            extract_example(PAGE),
+            Some(("1 < 2\n3 && 4".to_owned(), Some("42".to_owned())))
+        );
+    }
+
+    #[test]
+    fn skips_code_blocks_before_the_example() {
+        let page = PAGE.replace(
+            "<p>Some <code>intro</code> text.</p>",
+            "<pre><code>not it</code></pre>",
+        );
+        assert_eq!(extract_example(&page).unwrap().0, "1 < 2\n3 && 4");
+    }
+
+    #[test]
+    fn extracts_examples_without_answers() {
+        let page = PAGE.replace("<code><em>42</em></code>", "42");
+        assert_eq!(
+            extract_example(&page),
+            Some(("1 < 2\n3 && 4".to_owned(), None))
+        );
+    }
+
+    #[test]
+    fn finds_nothing_without_an_article_or_example() {
+        assert_eq!(extract_example("<html></html>"), None);
+        assert_eq!(
+            extract_example("<article><p>No code here.</p></article>"),
+            None
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{SolutionPart, TestCase, TestInput};
+
+/// The solution code for a single day, as handed to the [`Handler`](crate::Handler) by the mapper.
+///
+/// Usually built through one of the `Wrap*` traits, but can also be assembled from closures directly,
+/// which allows capturing configuration or building solvers at runtime.
+///
+/// ```
+/// use aoc_handler::Solver;
+///
+/// let width = 71;
+/// let solver = Solver::new(
+///     move |input: &str| input.lines().filter(|line| line.len() == width).count(),
+///     |_input: &str| {},
+/// )
+/// .with_part_1_test("abc\nde", 0);
+/// ```
+pub struct Solver {
+    pub(crate) parser: Option<Parser>,
+    pub(crate) part_1: SolverPart,
+    pub(crate) part_2: SolverPart,
+}
+
+/// Type-erased parse step, shared by both parts of a day.
+/// Without one, parts receive the raw input `String` directly.
+pub type Parser = Box<dyn Fn(&str) -> Box<dyn std::any::Any>>;
+
+pub struct SolverPart {
+    pub solver: PartSolver,
+    pub test_input: TestInput,
+    pub test_answer: SolutionPart,
+    pub test_cases: Vec<TestCase>,
+    pub alternatives: Vec<(String, PartSolver)>,
+}
+
+pub enum PartSolver {
+    Separate(Box<SeparateFn>),
+    /// Produces both parts at once. Both `SolverPart`s of a day hold the same function, and the result is shared between them.
+    Combined(std::rc::Rc<CombinedFn>),
+    /// Works on the raw input, regardless of whether the day has a parse step.
+    Raw(Box<RawFn>),
+}
+
+type SeparateFn = dyn Fn(&dyn std::any::Any) -> SolutionPart;
+type RawFn = dyn Fn(&str) -> SolutionPart;
+type CombinedFn = dyn Fn(&dyn std::any::Any) -> (SolutionPart, SolutionPart);
+
+impl Solver {
+    /// Builds a solver from one function per part, each working on the raw input.
+    pub fn new<P1: Into<SolutionPart>, P2: Into<SolutionPart>>(
+        part_1: impl Fn(&str) -> P1 + 'static,
+        part_2: impl Fn(&str) -> P2 + 'static,
+    ) -> Self {
+        Self::from_parts(
+            None,
+            PartSolver::Separate(Box::new(move |input| part_1(raw_input(input)).into())),
+            PartSolver::Separate(Box::new(move |input| part_2(raw_input(input)).into())),
+        )
+    }
+
+    /// Builds a solver whose parts share a parse step. The input is parsed once, and the parse time is reported separately.
+    pub fn parsed<Parsed: 'static, P1: Into<SolutionPart>, P2: Into<SolutionPart>>(
+        parse: impl Fn(&str) -> Parsed + 'static,
+        part_1: impl Fn(&Parsed) -> P1 + 'static,
+        part_2: impl Fn(&Parsed) -> P2 + 'static,
+    ) -> Self {
+        Self::from_parts(
+            Some(Box::new(move |input| Box::new(parse(input)))),
+            PartSolver::Separate(Box::new(move |input| {
+                part_1(parsed_input::<Parsed>(input)).into()
+            })),
+            PartSolver::Separate(Box::new(move |input| {
+                part_2(parsed_input::<Parsed>(input)).into()
+            })),
+        )
+    }
+
+    /// Builds a solver that produces both parts in one pass. The timing is shared between the parts.
+    pub fn combined<P1: Into<SolutionPart>, P2: Into<SolutionPart>>(
+        solve: impl Fn(&str) -> (P1, P2) + 'static,
+    ) -> Self {
+        let solve: std::rc::Rc<CombinedFn> = std::rc::Rc::new(move |input| {
+            let (part_1, part_2) = solve(raw_input(input));
+            (part_1.into(), part_2.into())
+        });
+        Self::from_parts(
+            None,
+            PartSolver::Combined(solve.clone()),
+            PartSolver::Combined(solve),
+        )
+    }
+
+    /// Sets the test input and expected answer for part one.
+    /// Unlike with [`DaySolver`](crate::DaySolver), part two does not inherit this input.
+    #[must_use]
+    pub fn with_part_1_test(
+        mut self,
+        input: impl Into<TestInput>,
+        answer: impl Into<SolutionPart>,
+    ) -> Self {
+        self.part_1.test_input = input.into();
+        self.part_1.test_answer = answer.into();
+        self
+    }
+
+    /// Sets the test input and expected answer for part two.
+    #[must_use]
+    pub fn with_part_2_test(
+        mut self,
+        input: impl Into<TestInput>,
+        answer: impl Into<SolutionPart>,
+    ) -> Self {
+        self.part_2.test_input = input.into();
+        self.part_2.test_answer = answer.into();
+        self
+    }
+
+    /// Adds named test cases for part one, checked in addition to its main test.
+    #[must_use]
+    pub fn with_part_1_test_cases(mut self, cases: impl IntoIterator<Item = TestCase>) -> Self {
+        self.part_1.test_cases.extend(cases);
+        self
+    }
+
+    /// Adds named test cases for part two, checked in addition to its main test.
+    #[must_use]
+    pub fn with_part_2_test_cases(mut self, cases: impl IntoIterator<Item = TestCase>) -> Self {
+        self.part_2.test_cases.extend(cases);
+        self
+    }
+
+    /// Adds alternative implementations of part one.
+    /// They are checked alongside the primary one, and have to agree with it when run on the real input.
+    #[must_use]
+    pub fn with_part_1_alternatives(
+        mut self,
+        alternatives: impl IntoIterator<Item = Implementation>,
+    ) -> Self {
+        self.part_1
+            .alternatives
+            .extend(alternatives.into_iter().map(Implementation::into_named));
+        self
+    }
+
+    /// Adds alternative implementations of part two.
+    /// They are checked alongside the primary one, and have to agree with it when run on the real input.
+    #[must_use]
+    pub fn with_part_2_alternatives(
+        mut self,
+        alternatives: impl IntoIterator<Item = Implementation>,
+    ) -> Self {
+        self.part_2
+            .alternatives
+            .extend(alternatives.into_iter().map(Implementation::into_named));
+        self
+    }
+
+    fn from_parts(parser: Option<Parser>, part_1: PartSolver, part_2: PartSolver) -> Self {
+        Self {
+            parser,
+            part_1: SolverPart {
+                solver: part_1,
+                test_input: TestInput::None,
+                test_answer: SolutionPart::Unimplemented,
+                test_cases: Vec::new(),
+                alternatives: Vec::new(),
+            },
+            part_2: SolverPart {
+                solver: part_2,
+                test_input: TestInput::None,
+                test_answer: SolutionPart::Unimplemented,
+                test_cases: Vec::new(),
+                alternatives: Vec::new(),
+            },
+        }
+    }
+}
+
+/// A named alternative implementation of a part, for comparing e.g. a naive and an optimised version.
+///
+/// Alternatives work either on the raw input, or on the output of the day's parse step.
+/// Alternatives that return `()` are shown as skipped.
+///
+/// ```
+/// use aoc_handler::Implementation;
+///
+/// let naive = Implementation::new("Naive", |input: &str| input.lines().count());
+/// let parsed = Implementation::parsed("Parsed", |input: &Vec<u32>| input.len());
+/// ```
+pub struct Implementation {
+    name: String,
+    solver: PartSolver,
+}
+
+impl Implementation {
+    /// An alternative working on the raw input, even if the day has a parse step.
+    pub fn new<P: Into<SolutionPart>>(
+        name: impl Into<String>,
+        solver: impl Fn(&str) -> P + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            solver: PartSolver::Raw(Box::new(move |input| solver(input).into())),
+        }
+    }
+
+    /// An alternative sharing the parsed input with the primary implementation, e.g. of a [`ParsedDaySolver`](crate::ParsedDaySolver).
+    /// `Parsed` has to be what the day's parse step produces, or `String` if there is none.
+    pub fn parsed<Parsed: 'static, P: Into<SolutionPart>>(
+        name: impl Into<String>,
+        solver: impl Fn(&Parsed) -> P + 'static,
+    ) -> Self {
+        let name = name.into();
+        let message = format!(
+            "Alternative {name:?} expects a different input than the day's parse step produces."
+        );
+        Self {
+            name,
+            solver: PartSolver::Separate(Box::new(move |input| {
+                solver(input.downcast_ref::<Parsed>().expect(&message)).into()
+            })),
+        }
+    }
+
+    fn into_named(self) -> (String, PartSolver) {
+        (self.name, self.solver)
+    }
+}
+
+fn raw_input(input: &dyn std::any::Any) -> &str {
+    input
+        .downcast_ref::<String>()
+        .expect("Internal error: Unparsed solver was handed parsed input.")
+}
+
+fn parsed_input<Parsed: 'static>(input: &dyn std::any::Any) -> &Parsed {
+    input
+        .downcast_ref::<Parsed>()
+        .expect("Internal error: Parsed solver was handed input of the wrong type.")
+}
@@ -35,7 +35,7 @@ pub enum PartInternal {
 }
 
 impl<Detail: TimeDetail> TimeKey<Detail> {
-    fn max_days(year: Year) -> Day {
+    pub(crate) const fn max_days(year: Year) -> Day {
         match year {
             2015..2025 => 25,
             2025.. => 12,
@@ -165,6 +165,17 @@ impl TimeKey<TimeDetailDayAndPart> {
     }
 }
 
+/// The current year, going by the system clock.
+#[cfg(any(feature = "cli", feature = "interactive"))]
+pub fn current_year() -> Year {
+    //? Accurate to within a day around new year, which is far from December either way
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(2015, |since| 1970 + since.as_secs() / 31_556_952)
+        .try_into()
+        .unwrap_or(Year::MAX)
+}
+
 #[derive(thiserror::Error, Debug, PartialEq, Eq, Hash)]
 pub enum TimeKeyError {
     #[error("Invalid Year: Advent of Code started in 2015")]